@@ -145,6 +145,8 @@ fn parse_perp_instrument(
         Some(margin_maint),
         Some(maker_fee),
         Some(taker_fee),
+        None,
+        None,
         ts_init, // ts_event same as ts_init (no local timestamp)
         ts_init,
     );
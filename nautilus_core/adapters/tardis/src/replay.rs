@@ -20,7 +20,7 @@ use std::{
 };
 
 use arrow::array::RecordBatch;
-use chrono::{DateTime, Duration, NaiveDate};
+use chrono::{Duration, NaiveDate};
 use futures_util::{future::join_all, pin_mut, StreamExt};
 use heck::ToSnakeCase;
 use nautilus_core::{nanos::UnixNanos, parsing::precision_from_str};
@@ -63,7 +63,7 @@ struct DateCursor {
 impl DateCursor {
     /// Creates a new [`DateCursor`] instance.
     fn new(current_ns: UnixNanos) -> Self {
-        let current_utc = DateTime::from_timestamp_nanos(current_ns.as_i64());
+        let current_utc = current_ns.to_datetime_utc();
         let date_utc = current_utc.date_naive();
 
         // Calculate end of the current UTC day
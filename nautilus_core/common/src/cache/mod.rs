@@ -46,7 +46,7 @@ use nautilus_model::{
         AccountId, ClientId, ClientOrderId, ComponentId, ExecAlgorithmId, InstrumentId,
         OrderListId, PositionId, StrategyId, Symbol, Venue, VenueOrderId,
     },
-    instruments::{InstrumentAny, SyntheticInstrument},
+    instruments::{InstrumentAny, InstrumentError, SyntheticInstrument},
     orderbook::OrderBook,
     orders::{OrderAny, OrderList},
     position::Position,
@@ -219,6 +219,7 @@ pub struct Cache {
     currencies: HashMap<Ustr, Currency>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
     synthetics: HashMap<InstrumentId, SyntheticInstrument>,
+    related_instruments: HashMap<InstrumentId, Vec<InstrumentId>>,
     accounts: HashMap<AccountId, AccountAny>,
     orders: HashMap<ClientOrderId, OrderAny>,
     order_lists: HashMap<OrderListId, OrderList>,
@@ -287,6 +288,7 @@ impl Cache {
             currencies: HashMap::new(),
             instruments: HashMap::new(),
             synthetics: HashMap::new(),
+            related_instruments: HashMap::new(),
             accounts: HashMap::new(),
             orders: HashMap::new(),
             order_lists: HashMap::new(),
@@ -968,6 +970,7 @@ impl Cache {
         self.currencies.clear();
         self.instruments.clear();
         self.synthetics.clear();
+        self.related_instruments.clear();
         self.accounts.clear();
         self.orders.clear();
         self.order_lists.clear();
@@ -1180,6 +1183,35 @@ impl Cache {
         Ok(())
     }
 
+    /// Sets the `related` instruments (such as quarterly futures on the same underlying as a
+    /// perpetual) for the given `instrument_id`, replacing any previously set.
+    ///
+    /// Relations are held in memory only and are not written to the cache database.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `related` contains an instrument ID more than once.
+    pub fn add_related_instruments(
+        &mut self,
+        instrument_id: InstrumentId,
+        related: Vec<InstrumentId>,
+    ) -> anyhow::Result<()> {
+        log::debug!("Adding related instruments for {instrument_id}");
+
+        let mut seen = HashSet::with_capacity(related.len());
+        if let Some(duplicate) = related.iter().find(|id| !seen.insert(**id)) {
+            return Err(InstrumentError::DuplicateRelatedInstrument {
+                instrument_id,
+                related: *duplicate,
+            }
+            .into());
+        }
+
+        self.related_instruments.insert(instrument_id, related);
+        Ok(())
+    }
+
     /// Adds the given `synthetic` instrument to the cache.
     pub fn add_synthetic(&mut self, synthetic: SyntheticInstrument) -> anyhow::Result<()> {
         log::debug!("Adding `SyntheticInstrument` {}", synthetic.id);
@@ -2681,6 +2713,15 @@ impl Cache {
             .collect()
     }
 
+    /// Returns the related instruments for the given `instrument_id`, so a strategy can
+    /// discover hedge or rollover instruments (empty if none were added).
+    #[must_use]
+    pub fn related_instruments(&self, instrument_id: &InstrumentId) -> &[InstrumentId] {
+        self.related_instruments
+            .get(instrument_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
     /// Returns references to all bar types contained in the cache.
     #[must_use]
     pub fn bar_types(
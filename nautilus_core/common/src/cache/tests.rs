@@ -21,8 +21,8 @@ use nautilus_model::{
     data::{Bar, QuoteTick, TradeTick},
     enums::{BookType, OmsType, OrderSide, OrderStatus, OrderType},
     events::{OrderAccepted, OrderEventAny, OrderRejected, OrderSubmitted},
-    identifiers::{AccountId, ClientOrderId, PositionId, Venue},
-    instruments::{stubs::*, CurrencyPair, InstrumentAny, SyntheticInstrument},
+    identifiers::{AccountId, ClientOrderId, InstrumentId, PositionId, Venue},
+    instruments::{stubs::*, CurrencyPair, InstrumentAny, InstrumentError, SyntheticInstrument},
     orderbook::OrderBook,
     orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
     position::Position,
@@ -453,6 +453,43 @@ fn test_instruments_when_some(mut cache: Cache) {
     assert_eq!(result2, vec![&InstrumentAny::FuturesContract(esz1)]);
 }
 
+#[rstest]
+fn test_related_instruments(mut cache: Cache) {
+    let perpetual = InstrumentId::from("BTCUSD-PERP.SIM");
+    let quarterly = InstrumentId::from("BTCUSD-241227.SIM");
+    let next_quarterly = InstrumentId::from("BTCUSD-250328.SIM");
+    assert!(cache.related_instruments(&perpetual).is_empty());
+
+    cache
+        .add_related_instruments(perpetual, vec![quarterly, next_quarterly])
+        .unwrap();
+
+    assert_eq!(
+        cache.related_instruments(&perpetual),
+        &[quarterly, next_quarterly]
+    );
+    assert!(cache.related_instruments(&quarterly).is_empty());
+}
+
+#[rstest]
+fn test_add_related_instruments_with_duplicate(mut cache: Cache) {
+    let perpetual = InstrumentId::from("BTCUSD-PERP.SIM");
+    let quarterly = InstrumentId::from("BTCUSD-241227.SIM");
+
+    let err = cache
+        .add_related_instruments(perpetual, vec![quarterly, quarterly])
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast_ref::<InstrumentError>(),
+        Some(&InstrumentError::DuplicateRelatedInstrument {
+            instrument_id: perpetual,
+            related: quarterly,
+        })
+    );
+    assert!(cache.related_instruments(&perpetual).is_empty());
+}
+
 #[rstest]
 fn test_cache_synthetics_when_no_database(mut cache: Cache) {
     assert!(cache.cache_synthetics().is_ok());
@@ -45,7 +45,7 @@ use crate::timer::{
 pub trait Clock {
     /// Returns the current date and time as a timezone-aware `DateTime<UTC>`.
     fn utc_now(&self) -> DateTime<Utc> {
-        DateTime::from_timestamp_nanos(self.timestamp_ns().as_i64())
+        self.timestamp_ns().to_datetime_utc()
     }
 
     /// Returns the current UNIX timestamp in nanoseconds (ns).
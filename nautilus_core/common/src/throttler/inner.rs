@@ -141,13 +141,14 @@ impl<T, F> InnerThrottler<T, F> {
             return 0.0;
         }
 
-        let now = self.clock.borrow().timestamp_ns().as_i64();
-        let interval_start = now - self.interval as i64;
+        let now = self.clock.borrow().timestamp_ns().as_u64();
 
+        // Compare in `u64`, a timestamp is in the current interval if it is less than
+        // `interval` nanoseconds before `now`
         let messages_in_current_interval = self
             .timestamps
             .iter()
-            .take_while(|&&ts| ts.as_i64() > interval_start)
+            .take_while(|&&ts| now.saturating_sub(ts.as_u64()) < self.interval)
             .count();
 
         (messages_in_current_interval as f64) / (self.limit as f64)
@@ -268,6 +268,24 @@ mod tests {
         assert_eq!(inner.sent_count, 3);
     }
 
+    #[rstest]
+    fn test_buffering_used_with_timestamps_beyond_i64_max(
+        mut test_throttler_buffered: TestThrottler,
+    ) {
+        {
+            let mut clock = test_throttler_buffered.clock.borrow_mut();
+            clock.advance_time((i64::MAX as u64 + 1).into(), true);
+        }
+        let throttler = &mut test_throttler_buffered.throttler;
+
+        for _ in 0..3 {
+            throttler.send(42);
+        }
+
+        let inner = throttler.inner.borrow();
+        assert_eq!(inner.used(), 0.6);
+    }
+
     #[rstest]
     fn test_buffering_refresh_when_at_limit_sends_remaining_items(
         mut test_throttler_buffered: TestThrottler,
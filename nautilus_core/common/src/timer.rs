@@ -300,6 +300,7 @@ impl TestTimer {
     /// This allows testing of multiple time intervals within a single step.
     pub fn advance(&mut self, to_time_ns: UnixNanos) -> impl Iterator<Item = TimeEvent> + '_ {
         let advances = to_time_ns
+            .as_u64()
             .saturating_sub(self.next_time_ns.as_u64() - self.interval_ns.get())
             / self.interval_ns.get();
         self.take(advances as usize).map(|(event, _)| event)
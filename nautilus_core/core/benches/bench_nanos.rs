@@ -0,0 +1,46 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{fmt::Write, hint::black_box};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use nautilus_core::nanos::UnixNanos;
+
+const NANOS: UnixNanos = UnixNanos::MAX;
+
+// Using the `Display` impl through the formatting machinery
+fn bench_display(c: &mut Criterion) {
+    let mut buf = String::with_capacity(32);
+    c.bench_function("UnixNanos display", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{}", black_box(NANOS)).unwrap();
+        });
+    });
+}
+
+// Using `write_to` directly (an `itoa` fast path with the `itoa` feature)
+fn bench_write_to(c: &mut Criterion) {
+    let mut buf = String::with_capacity(32);
+    c.bench_function("UnixNanos write_to", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(NANOS).write_to(&mut buf).unwrap();
+        });
+    });
+}
+
+criterion_group!(benches, bench_display, bench_write_to);
+criterion_main!(benches);
@@ -18,13 +18,45 @@
 use std::{
     cmp::Ordering,
     fmt::Display,
-    ops::{Add, AddAssign, Deref, Sub, SubAssign},
+    ops::{Add, AddAssign, Deref, Neg, Sub, SubAssign},
     str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    correctness::{check_in_range_inclusive_u64, FAILED},
+    datetime::{NANOSECONDS_IN_MICROSECOND, NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    time::duration_since_unix_epoch,
+};
+
+/// The maximum number of seconds since the UNIX epoch representable as [`UnixNanos`].
+pub const MAX_SECS: u64 = u64::MAX / NANOSECONDS_IN_SECOND;
+
+/// The maximum number of milliseconds since the UNIX epoch representable as [`UnixNanos`].
+pub const MAX_MILLIS: u64 = u64::MAX / NANOSECONDS_IN_MILLISECOND;
+
+/// The maximum number of microseconds since the UNIX epoch representable as [`UnixNanos`].
+pub const MAX_MICROS: u64 = u64::MAX / NANOSECONDS_IN_MICROSECOND;
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+// Guard the unit constructors against the limits drifting from the conversion factors
+const _: () = {
+    assert!(MAX_SECS.checked_mul(NANOSECONDS_IN_SECOND).is_some());
+    assert!((MAX_SECS + 1).checked_mul(NANOSECONDS_IN_SECOND).is_none());
+    assert!(MAX_MILLIS.checked_mul(NANOSECONDS_IN_MILLISECOND).is_some());
+    assert!((MAX_MILLIS + 1)
+        .checked_mul(NANOSECONDS_IN_MILLISECOND)
+        .is_none());
+    assert!(MAX_MICROS.checked_mul(NANOSECONDS_IN_MICROSECOND).is_some());
+    assert!((MAX_MICROS + 1)
+        .checked_mul(NANOSECONDS_IN_MICROSECOND)
+        .is_none());
+};
+
 /// Represents a timestamp in nanoseconds since the UNIX epoch.
 #[repr(C)]
 #[derive(
@@ -33,16 +65,226 @@ use serde::{Deserialize, Serialize};
 pub struct UnixNanos(u64);
 
 impl UnixNanos {
+    /// The UNIX epoch (zero nanoseconds).
+    pub const ZERO: Self = Self(0);
+
+    /// The minimum representable timestamp (the UNIX epoch).
+    pub const MIN: Self = Self(u64::MIN);
+
+    /// The maximum representable timestamp.
+    pub const MAX: Self = Self(u64::MAX);
+
+    /// Creates a new [`UnixNanos`] instance from the current system clock time.
+    ///
+    /// Saturates at `u64::MAX` should the system clock be beyond the representable range.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the system time is set before the UNIX epoch.
+    #[must_use]
+    pub fn now() -> Self {
+        Self(u64::try_from(duration_since_unix_epoch().as_nanos()).unwrap_or(u64::MAX))
+    }
+
+    /// Creates a new [`UnixNanos`] instance from the given `secs` since the UNIX epoch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `secs` exceeds [`MAX_SECS`], the largest value representable in nanoseconds.
+    pub fn try_from_secs(secs: u64) -> anyhow::Result<Self> {
+        match secs.checked_mul(NANOSECONDS_IN_SECOND) {
+            Some(nanos) => Ok(Self(nanos)),
+            None => anyhow::bail!("Invalid `secs` {secs}, exceeded maximum {MAX_SECS}"),
+        }
+    }
+
+    /// Creates a new [`UnixNanos`] instance from the given `millis` since the UNIX epoch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `millis` exceeds [`MAX_MILLIS`], the largest value representable in nanoseconds.
+    pub fn try_from_millis(millis: u64) -> anyhow::Result<Self> {
+        match millis.checked_mul(NANOSECONDS_IN_MILLISECOND) {
+            Some(nanos) => Ok(Self(nanos)),
+            None => anyhow::bail!("Invalid `millis` {millis}, exceeded maximum {MAX_MILLIS}"),
+        }
+    }
+
+    /// Creates a new [`UnixNanos`] instance from the given `micros` since the UNIX epoch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `micros` exceeds [`MAX_MICROS`], the largest value representable in nanoseconds.
+    pub fn try_from_micros(micros: u64) -> anyhow::Result<Self> {
+        match micros.checked_mul(NANOSECONDS_IN_MICROSECOND) {
+            Some(nanos) => Ok(Self(nanos)),
+            None => anyhow::bail!("Invalid `micros` {micros}, exceeded maximum {MAX_MICROS}"),
+        }
+    }
+
+    /// Creates a new [`UnixNanos`] instance from the given `secs` since the UNIX epoch.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `secs` exceeds [`MAX_SECS`] (around the year 2554).
+    #[must_use]
+    pub fn from_secs(secs: u64) -> Self {
+        Self::try_from_secs(secs).expect(FAILED)
+    }
+
+    /// Creates a new [`UnixNanos`] instance from the given `millis` since the UNIX epoch.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `millis` exceeds [`MAX_MILLIS`].
+    #[must_use]
+    pub fn from_millis(millis: u64) -> Self {
+        Self::try_from_millis(millis).expect(FAILED)
+    }
+
+    /// Creates a new [`UnixNanos`] instance from the given `micros` since the UNIX epoch.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `micros` exceeds [`MAX_MICROS`].
+    #[must_use]
+    pub fn from_micros(micros: u64) -> Self {
+        Self::try_from_micros(micros).expect(FAILED)
+    }
+
+    /// Creates a new [`UnixNanos`] instance from a `value` in nanoseconds relative to a custom
+    /// epoch, which itself is `epoch_offset_ns` nanoseconds after the UNIX epoch.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the resulting timestamp overflows `u64`.
+    #[must_use]
+    pub fn from_epoch_offset(value: u64, epoch_offset_ns: u64) -> Self {
+        Self(
+            value
+                .checked_add(epoch_offset_ns)
+                .expect("Error applying epoch offset with overflow"),
+        )
+    }
+
+    /// Creates a new [`UnixNanos`] instance from whole `secs` and the sub-second `nanos`
+    /// remainder since the UNIX epoch (the shape of a protobuf `Timestamp`).
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `nanos` is not less than one second (1,000,000,000).
+    /// - If `secs` exceeds [`MAX_SECS`], or the combined value overflows `u64`.
+    #[must_use]
+    pub fn from_secs_nanos(secs: u64, nanos: u32) -> Self {
+        check_in_range_inclusive_u64(
+            u64::from(nanos),
+            0,
+            NANOSECONDS_IN_SECOND - 1,
+            stringify!(nanos),
+        )
+        .expect(FAILED);
+        Self(
+            Self::from_secs(secs)
+                .0
+                .checked_add(u64::from(nanos))
+                .expect("Error adding with overflow"),
+        )
+    }
+
+    /// Creates a new [`UnixNanos`] instance from the given `duration` since the UNIX epoch.
+    ///
+    /// Saturates at `u64::MAX` if the duration exceeds the representable range.
+    #[must_use]
+    pub fn from_duration_since_epoch(duration: Duration) -> Self {
+        Self(u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX))
+    }
+
+    /// Returns the [`Duration`] elapsed from `earlier` to this timestamp, or `None`
+    /// if `earlier` is later than this timestamp.
+    #[must_use]
+    pub fn duration_since(self, earlier: Self) -> Option<Duration> {
+        self.0.checked_sub(earlier.0).map(Duration::from_nanos)
+    }
+
+    /// Returns the value offset by the signed `delta`, or `None` if the result is outside the
+    /// representable range.
+    #[must_use]
+    pub const fn checked_add_timedelta(self, delta: Timedelta) -> Option<Self> {
+        self.checked_add_i64(delta.0)
+    }
+
+    /// Returns the value offset by the negated signed `delta`, or `None` if the result is
+    /// outside the representable range.
+    #[must_use]
+    pub const fn checked_sub_timedelta(self, delta: Timedelta) -> Option<Self> {
+        self.checked_sub_i64(delta.0)
+    }
+
+    /// Returns the value offset by the signed `delta`, saturating at the representable bounds.
+    #[must_use]
+    pub const fn saturating_add_timedelta(self, delta: Timedelta) -> Self {
+        self.saturating_add_i64(delta.0)
+    }
+
+    /// Returns the value offset by the negated signed `delta`, saturating at the representable
+    /// bounds.
+    #[must_use]
+    pub const fn saturating_sub_timedelta(self, delta: Timedelta) -> Self {
+        self.saturating_sub_i64(delta.0)
+    }
+
+    /// Returns the sum of all the given values, or `None` if the sum overflows.
+    #[must_use]
+    pub fn sum(iter: impl IntoIterator<Item = Self>) -> Option<Self> {
+        iter.into_iter()
+            .try_fold(Self::ZERO, |acc, value| acc.checked_add(value))
+    }
+
+    /// Returns an iterator of timestamps from `start` (inclusive) to `end` (exclusive), stepping
+    /// by `step` nanoseconds.
+    ///
+    /// When `step` does not evenly divide the range, the last timestamp is the final step before
+    /// `end`. A zero `step` (or `start >= end`) yields an empty iterator.
+    pub fn step_range(start: Self, end: Self, step: DurationNanos) -> impl Iterator<Item = Self> {
+        let first = (step > 0 && start < end).then_some(start);
+        std::iter::successors(first, move |current| {
+            current
+                .0
+                .checked_add(step)
+                .map(Self)
+                .filter(|next| *next < end)
+        })
+    }
+
+    /// Returns `true` if the value is zero (the UNIX epoch).
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
     /// Returns the underlying value as `u64`.
     #[must_use]
     pub const fn as_u64(&self) -> u64 {
         self.0
     }
 
-    /// Returns the underlying value as `i64`.
+    /// Returns the underlying value as `i64`, or `None` if the value exceeds `i64::MAX`.
     #[must_use]
-    pub const fn as_i64(&self) -> i64 {
-        self.0 as i64
+    pub const fn as_i64(&self) -> Option<i64> {
+        if self.0 > i64::MAX as u64 {
+            None
+        } else {
+            Some(self.0 as i64)
+        }
     }
 
     /// Returns the underlying value as `f64`.
@@ -50,8 +292,316 @@ impl UnixNanos {
     pub const fn as_f64(&self) -> f64 {
         self.0 as f64
     }
+
+    /// Creates a new [`UnixNanos`] instance from the given UTC `datetime`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `datetime` is before the UNIX epoch.
+    /// - If `datetime` is out of range for a nanosecond timestamp.
+    pub fn try_from_datetime(datetime: DateTime<Utc>) -> anyhow::Result<Self> {
+        let nanos = datetime
+            .timestamp_nanos_opt()
+            .ok_or_else(|| anyhow::anyhow!("Datetime {datetime} out of range for nanoseconds"))?;
+        if nanos < 0 {
+            anyhow::bail!("Datetime {datetime} is before the UNIX epoch");
+        }
+        Ok(Self(nanos as u64))
+    }
+
+    /// Returns the value split into whole seconds and the sub-second nanoseconds remainder
+    /// since the UNIX epoch (the shape of a protobuf `Timestamp`).
+    #[must_use]
+    pub const fn to_secs_nanos(&self) -> (u64, u32) {
+        (
+            self.0 / NANOSECONDS_IN_SECOND,
+            (self.0 % NANOSECONDS_IN_SECOND) as u32,
+        )
+    }
+
+    /// Returns the value as a UTC [`DateTime`].
+    #[must_use]
+    pub fn to_datetime_utc(&self) -> DateTime<Utc> {
+        let secs = self.0 / NANOSECONDS_IN_SECOND;
+        let nanos = self.0 % NANOSECONDS_IN_SECOND;
+        DateTime::from_timestamp(secs as i64, nanos as u32)
+            .expect("`UnixNanos` should always be within the `DateTime` range")
+    }
+
+    /// Creates a new [`UnixNanos`] instance by parsing the given RFC 3339 string.
+    ///
+    /// Both the `Z` suffix and explicit UTC offsets are accepted, with the value converted to UTC.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `s` is not a valid RFC 3339 string.
+    /// - If the parsed datetime is before the UNIX epoch.
+    pub fn from_rfc3339(s: &str) -> anyhow::Result<Self> {
+        let datetime = DateTime::parse_from_rfc3339(s)?.with_timezone(&Utc);
+        Self::try_from_datetime(datetime)
+    }
+
+    /// Returns the value as an RFC 3339 string with all nine fractional digits (UTC, `Z` suffix).
+    #[must_use]
+    pub fn to_rfc3339(&self) -> String {
+        self.to_datetime_utc()
+            .to_rfc3339_opts(SecondsFormat::Nanos, true)
+    }
+
+    /// Returns a human-readable representation of the value (RFC 3339 UTC with nanoseconds).
+    ///
+    /// This is also used by the alternate `{:#}` format, while the standard `{}` format remains
+    /// the raw integer.
+    #[must_use]
+    pub fn to_human(&self) -> String {
+        self.to_rfc3339()
+    }
+
+    /// Returns the number of whole seconds since the UNIX epoch (truncated toward zero).
+    #[must_use]
+    pub const fn as_secs(&self) -> u64 {
+        self.0 / NANOSECONDS_IN_SECOND
+    }
+
+    /// Returns the number of whole milliseconds since the UNIX epoch (truncated toward zero).
+    #[must_use]
+    pub const fn as_millis(&self) -> u64 {
+        self.0 / NANOSECONDS_IN_MILLISECOND
+    }
+
+    /// Returns the number of whole microseconds since the UNIX epoch (truncated toward zero).
+    #[must_use]
+    pub const fn as_micros(&self) -> u64 {
+        self.0 / NANOSECONDS_IN_MICROSECOND
+    }
+
+    /// Returns the earlier of this timestamp and `other`.
+    #[must_use]
+    pub const fn min(self, other: Self) -> Self {
+        if other.0 < self.0 {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the later of this timestamp and `other`.
+    #[must_use]
+    pub const fn max(self, other: Self) -> Self {
+        if other.0 > self.0 {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns this timestamp clamped to the window [`min`, `max`].
+    ///
+    /// In debug builds this function asserts that `min` is not after `max`.
+    #[must_use]
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        debug_assert!(min.0 <= max.0, "Invalid window: `min` was after `max`");
+        self.max(min).min(max)
+    }
+
+    /// Returns this timestamp rounded down to a multiple of `interval_ns` (the start of its
+    /// containing interval).
+    ///
+    /// A zero `interval_ns` returns this timestamp unchanged.
+    #[must_use]
+    pub const fn floor_to(self, interval_ns: DurationNanos) -> Self {
+        if interval_ns == 0 {
+            return self;
+        }
+        Self(self.0 - self.0 % interval_ns)
+    }
+
+    /// Returns this timestamp rounded up to a multiple of `interval_ns` (the end of its
+    /// containing interval). A timestamp already on a boundary is returned unchanged.
+    ///
+    /// A zero `interval_ns` returns this timestamp unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the rounded timestamp overflows `u64::MAX`.
+    #[must_use]
+    pub const fn ceil_to(self, interval_ns: DurationNanos) -> Self {
+        if interval_ns == 0 {
+            return self;
+        }
+        let remainder = self.0 % interval_ns;
+        if remainder == 0 {
+            return self;
+        }
+        match self.0.checked_add(interval_ns - remainder) {
+            Some(value) => Self(value),
+            None => panic!("Error aligning with overflow"),
+        }
+    }
+
+    /// Returns the start (midnight UTC) of the UTC day containing this timestamp.
+    ///
+    /// UTC days are always exactly 86,400 seconds, so the result is unaffected by daylight
+    /// saving time and is aligned to a multiple of a day since the UNIX epoch.
+    #[must_use]
+    pub const fn start_of_utc_day(&self) -> Self {
+        self.floor_to(NANOSECONDS_IN_DAY)
+    }
+
+    /// Returns the start (Monday midnight UTC) of the ISO week containing this timestamp.
+    ///
+    /// The UNIX epoch was a Thursday, so the week containing the first four days of 1970 began
+    /// before the epoch; the result saturates at [`UnixNanos::ZERO`] for these timestamps.
+    #[must_use]
+    pub const fn start_of_utc_week(&self) -> Self {
+        let days = self.0 / NANOSECONDS_IN_DAY;
+        let days_from_monday = (days + 3) % 7; // Epoch day zero was a Thursday
+        Self(days.saturating_sub(days_from_monday) * NANOSECONDS_IN_DAY)
+    }
+
+    /// Adds `rhs` to this timestamp, returning `None` if overflow occurred.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from this timestamp, returning `None` if underflow occurred.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Offsets this timestamp by the signed `delta`, returning `None` if the result
+    /// would fall outside the representable range.
+    #[must_use]
+    pub const fn checked_add_i64(self, delta: TimedeltaNanos) -> Option<Self> {
+        let result = if delta >= 0 {
+            self.0.checked_add(delta.unsigned_abs())
+        } else {
+            self.0.checked_sub(delta.unsigned_abs())
+        };
+        match result {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Offsets this timestamp by the negation of the signed `delta`, returning `None` if the
+    /// result would fall outside the representable range.
+    #[must_use]
+    pub const fn checked_sub_i64(self, delta: TimedeltaNanos) -> Option<Self> {
+        let result = if delta >= 0 {
+            self.0.checked_sub(delta.unsigned_abs())
+        } else {
+            self.0.checked_add(delta.unsigned_abs())
+        };
+        match result {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Adds `rhs` to this timestamp, saturating at `u64::MAX` instead of overflowing.
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` from this timestamp, saturating at zero instead of underflowing.
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Offsets this timestamp by the signed `delta`, saturating at zero or `u64::MAX`.
+    #[must_use]
+    pub const fn saturating_add_i64(self, delta: TimedeltaNanos) -> Self {
+        if delta >= 0 {
+            Self(self.0.saturating_add(delta.unsigned_abs()))
+        } else {
+            Self(self.0.saturating_sub(delta.unsigned_abs()))
+        }
+    }
+
+    /// Offsets this timestamp by the negation of the signed `delta`, saturating at zero or `u64::MAX`.
+    #[must_use]
+    pub const fn saturating_sub_i64(self, delta: TimedeltaNanos) -> Self {
+        if delta >= 0 {
+            Self(self.0.saturating_sub(delta.unsigned_abs()))
+        } else {
+            Self(self.0.saturating_add(delta.unsigned_abs()))
+        }
+    }
+
+    /// Scales this value by `factor`, rounding to the nearest nanosecond.
+    ///
+    /// This is intended for values representing durations only; scaling an absolute
+    /// timestamp is almost never meaningful. The result saturates at zero and `u64::MAX`
+    /// (a `NaN` factor yields zero).
+    #[must_use]
+    pub fn scale_duration(self, factor: f64) -> Self {
+        // Float to integer `as` casts saturate at the target bounds
+        Self((self.0 as f64 * factor).round() as u64)
+    }
+
+    /// Writes the raw integer value to `buf`, as per the [`Display`] impl, without allocating.
+    ///
+    /// With the `itoa` feature enabled the digits are rendered with `itoa`, bypassing the
+    /// standard formatting machinery for hot logging paths.
+    pub fn write_to(&self, buf: &mut impl std::fmt::Write) -> std::fmt::Result {
+        #[cfg(feature = "itoa")]
+        {
+            buf.write_str(itoa::Buffer::new().format(self.0))
+        }
+        #[cfg(not(feature = "itoa"))]
+        {
+            write!(buf, "{}", self.0)
+        }
+    }
+
+    /// Returns the signed difference `self - other` in nanoseconds, or `None` if the
+    /// magnitude is not representable as an `i64`.
+    #[must_use]
+    pub fn checked_diff(self, other: Self) -> Option<TimedeltaNanos> {
+        TimedeltaNanos::try_from(i128::from(self.0) - i128::from(other.0)).ok()
+    }
+
+    /// Returns the signed difference `self - other` in nanoseconds.
+    ///
+    /// The result is negative when `other` is later than `self`, and saturates at
+    /// `i64::MIN` or `i64::MAX` if the magnitude is not representable.
+    #[must_use]
+    pub fn diff(self, other: Self) -> TimedeltaNanos {
+        let diff = i128::from(self.0) - i128::from(other.0);
+        diff.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as TimedeltaNanos
+    }
+
+    /// Returns the signed time elapsed from `earlier` to `self` in nanoseconds.
+    ///
+    /// For latency metrics call this on the current time taken from the clock in use, as in
+    /// `now.elapsed_since(event_ts)`, so the result is deterministic under a test clock.
+    ///
+    /// The result is negative when `earlier` is later than `self` (e.g. an event stamped by a
+    /// skewed venue clock), and saturates at `i64::MIN` or `i64::MAX` if the magnitude is not
+    /// representable.
+    #[must_use]
+    pub fn elapsed_since(&self, earlier: Self) -> TimedeltaNanos {
+        self.diff(earlier)
+    }
 }
 
+/// Dereferences to the underlying `u64` nanoseconds value.
+///
+/// Prefer the explicit [`UnixNanos::as_u64`], which is also usable in const contexts.
 impl Deref for UnixNanos {
     type Target = u64;
 
@@ -96,6 +646,18 @@ impl From<u64> for UnixNanos {
     }
 }
 
+impl TryFrom<i64> for UnixNanos {
+    type Error = anyhow::Error;
+
+    /// Converts the signed `value`, returning an error if it is negative (before the UNIX epoch).
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        if value < 0 {
+            anyhow::bail!("Invalid `UnixNanos` from negative value {value}");
+        }
+        Ok(Self(value as u64))
+    }
+}
+
 impl From<UnixNanos> for u64 {
     fn from(value: UnixNanos) -> Self {
         value.0
@@ -120,10 +682,50 @@ impl From<String> for UnixNanos {
 
 impl From<DateTime<Utc>> for UnixNanos {
     fn from(value: DateTime<Utc>) -> Self {
-        Self::from(value.timestamp_nanos_opt().expect("Invalid timestamp") as u64)
+        Self::try_from_datetime(value).expect(FAILED)
+    }
+}
+
+impl From<UnixNanos> for DateTime<Utc> {
+    fn from(value: UnixNanos) -> Self {
+        value.to_datetime_utc()
+    }
+}
+
+impl TryFrom<SystemTime> for UnixNanos {
+    type Error = anyhow::Error;
+
+    /// Converts the `value`, returning an error if it is before the UNIX epoch or
+    /// exceeds the representable range.
+    fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
+        let duration = value
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| anyhow::anyhow!("Invalid `SystemTime` before the UNIX epoch: {e}"))?;
+        let nanos = u64::try_from(duration.as_nanos()).map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid `SystemTime` {duration:?} since epoch, exceeded `u64` nanoseconds"
+            )
+        })?;
+        Ok(Self(nanos))
+    }
+}
+
+impl TryFrom<UnixNanos> for SystemTime {
+    type Error = anyhow::Error;
+
+    /// Converts the `value`, returning an error if it exceeds the platform's `SystemTime` range.
+    fn try_from(value: UnixNanos) -> Result<Self, Self::Error> {
+        UNIX_EPOCH
+            .checked_add(Duration::from_nanos(value.0))
+            .ok_or_else(|| {
+                anyhow::anyhow!("Invalid `UnixNanos` {value}, exceeded `SystemTime` range")
+            })
     }
 }
 
+/// Parses a base-10 integer string of nanoseconds since the UNIX epoch.
+///
+/// The [`std::num::ParseIntError`] kind distinguishes empty, non-numeric and overflowing input.
 impl FromStr for UnixNanos {
     type Err = std::num::ParseIntError;
 
@@ -195,81 +797,652 @@ impl<T: Into<u64>> SubAssign<T> for UnixNanos {
 }
 
 impl Display for UnixNanos {
+    /// Formats the raw integer value, or with the alternate flag (`{:#}`) the human-readable
+    /// value as per [`UnixNanos::to_human`].
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        if f.alternate() {
+            write!(f, "{}", self.to_human())
+        } else {
+            self.write_to(f)
+        }
     }
 }
 
-/// Represents a duration in nanoseconds.
-pub type DurationNanos = u64;
+/// Compares two `(timestamp, sequence)` pairs, ordering by timestamp then by sequence.
+///
+/// This gives a total order over events so that those sharing the same timestamp are sorted
+/// deterministically by their sequence number.
+#[must_use]
+pub fn cmp_with_seq(a: (UnixNanos, u64), b: (UnixNanos, u64)) -> Ordering {
+    a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1))
+}
 
-////////////////////////////////////////////////////////////////////////////////
-// Tests
-////////////////////////////////////////////////////////////////////////////////
-#[cfg(test)]
-mod tests {
-    use rstest::rstest;
+/// Serde helpers for (de)serializing [`UnixNanos`] as a decimal string.
+///
+/// Intended for use with `#[serde(with = "nautilus_core::nanos::serde_as_str")]` where consumers
+/// cannot represent the full `u64` range as a number (e.g. JavaScript). Deserialization accepts
+/// either a decimal string or an integer for backwards compatibility.
+pub mod serde_as_str {
+    use serde::{
+        de::{Unexpected, Visitor},
+        Deserializer, Serializer,
+    };
 
-    use super::*;
+    use super::UnixNanos;
 
-    #[rstest]
-    fn test_new() {
-        let nanos = UnixNanos::from(123);
-        assert_eq!(nanos.as_u64(), 123);
-        assert_eq!(nanos.as_i64(), 123);
-    }
+    struct UnixNanosVisitor;
 
-    #[rstest]
-    fn test_default() {
-        let nanos = UnixNanos::default();
-        assert_eq!(nanos.as_u64(), 0);
-        assert_eq!(nanos.as_i64(), 0);
-    }
+    impl Visitor<'_> for UnixNanosVisitor {
+        type Value = UnixNanos;
 
-    #[rstest]
-    fn test_into_from() {
-        let nanos: UnixNanos = 456.into();
-        let value: u64 = nanos.into();
-        assert_eq!(value, 456);
-    }
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a non-negative integer or decimal string of nanoseconds")
+        }
 
-    #[rstest]
-    fn test_from_str() {
-        let nanos: UnixNanos = "123".parse().unwrap();
-        assert_eq!(nanos.as_u64(), 123);
-    }
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            value
+                .parse::<u64>()
+                .map(UnixNanos)
+                .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+        }
 
-    #[rstest]
-    fn test_from_str_invalid() {
-        let result = "abc".parse::<UnixNanos>();
-        assert!(result.is_err());
-    }
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(UnixNanos(value))
+        }
 
-    #[rstest]
-    fn test_try_from_datetime_valid() {
-        use chrono::TimeZone;
-        let datetime = Utc.timestamp_opt(1_000_000_000, 0).unwrap(); // 1 billion seconds since epoch
-        let nanos = UnixNanos::from(datetime);
-        assert_eq!(nanos.as_u64(), 1_000_000_000_000_000_000);
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            u64::try_from(value)
+                .map(UnixNanos)
+                .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+        }
     }
 
-    #[rstest]
-    fn test_eq() {
-        let nanos = UnixNanos::from(100);
-        assert_eq!(nanos, 100);
-        assert_eq!(nanos, Some(100));
-        assert_ne!(nanos, 200);
-        assert_ne!(nanos, Some(200));
-        assert_ne!(nanos, None);
+    /// Serializes the given `value` as a decimal string.
+    pub fn serialize<S>(value: &UnixNanos, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(value)
     }
 
-    #[rstest]
-    fn test_partial_cmp() {
-        let nanos = UnixNanos::from(100);
-        assert_eq!(nanos.partial_cmp(&100), Some(Ordering::Equal));
-        assert_eq!(nanos.partial_cmp(&200), Some(Ordering::Less));
-        assert_eq!(nanos.partial_cmp(&50), Some(Ordering::Greater));
-        assert_eq!(nanos.partial_cmp(&None), Some(Ordering::Greater));
+    /// Deserializes a [`UnixNanos`] from either a decimal string or an integer.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<UnixNanos, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(UnixNanosVisitor)
+    }
+}
+
+/// Represents a duration in nanoseconds.
+pub type DurationNanos = u64;
+
+/// Represents a signed duration (time delta) in nanoseconds.
+///
+/// Prefer the type-safe [`Timedelta`] for new code.
+pub type TimedeltaNanos = i64;
+
+/// Represents a signed duration (time delta) in nanoseconds.
+///
+/// Adding a negative `Timedelta` to a [`UnixNanos`] moves the timestamp earlier.
+#[repr(C)]
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct Timedelta(i64);
+
+impl Timedelta {
+    /// A zero duration.
+    pub const ZERO: Self = Self(0);
+
+    /// The minimum representable duration.
+    pub const MIN: Self = Self(i64::MIN);
+
+    /// The maximum representable duration.
+    pub const MAX: Self = Self(i64::MAX);
+
+    /// Creates a new [`Timedelta`] instance from the given signed `nanos`.
+    #[must_use]
+    pub const fn new(nanos: TimedeltaNanos) -> Self {
+        Self(nanos)
+    }
+
+    /// Returns the underlying value as `i64`.
+    #[must_use]
+    pub const fn as_i64(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns `true` if the duration is negative.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns the sum of the durations, or `None` if the result overflows.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Returns the difference of the durations, or `None` if the result overflows.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+}
+
+impl From<i64> for Timedelta {
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Timedelta> for i64 {
+    fn from(value: Timedelta) -> Self {
+        value.0
+    }
+}
+
+impl Add for Timedelta {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("Error adding with overflow")
+    }
+}
+
+impl Sub for Timedelta {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("Error subtracting with overflow")
+    }
+}
+
+impl Neg for Timedelta {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(self.0.checked_neg().expect("Error negating with overflow"))
+    }
+}
+
+impl Add<Timedelta> for UnixNanos {
+    type Output = Self;
+    fn add(self, rhs: Timedelta) -> Self::Output {
+        self.checked_add_timedelta(rhs)
+            .expect("Error adding with overflow")
+    }
+}
+
+impl Sub<Timedelta> for UnixNanos {
+    type Output = Self;
+    fn sub(self, rhs: Timedelta) -> Self::Output {
+        self.checked_sub_timedelta(rhs)
+            .expect("Error subtracting with underflow")
+    }
+}
+
+impl Display for Timedelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Represents a range of UNIX timestamps, always inclusive of the `start`.
+///
+/// The `end` is inclusive for a closed range, and exclusive for a half-open range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NanosRange {
+    /// The start of the range (inclusive).
+    pub start: UnixNanos,
+    /// The end of the range.
+    pub end: UnixNanos,
+    /// If the `end` is included in the range.
+    pub inclusive_end: bool,
+}
+
+impl NanosRange {
+    /// Creates a new closed [`NanosRange`] instance `[start, end]`.
+    #[must_use]
+    pub const fn closed(start: UnixNanos, end: UnixNanos) -> Self {
+        Self {
+            start,
+            end,
+            inclusive_end: true,
+        }
+    }
+
+    /// Creates a new half-open [`NanosRange`] instance `[start, end)`.
+    #[must_use]
+    pub const fn half_open(start: UnixNanos, end: UnixNanos) -> Self {
+        Self {
+            start,
+            end,
+            inclusive_end: false,
+        }
+    }
+
+    /// Returns whether the given timestamp `ts` is within the range.
+    #[must_use]
+    pub fn contains(&self, ts: UnixNanos) -> bool {
+        if self.inclusive_end {
+            self.start <= ts && ts <= self.end
+        } else {
+            self.start <= ts && ts < self.end
+        }
+    }
+
+    /// Returns the duration from `start` to `end` in nanoseconds.
+    ///
+    /// Returns `None` if the range is inverted (`end` before `start`), or if the duration
+    /// exceeds the `TimedeltaNanos` range.
+    #[must_use]
+    pub fn duration(&self) -> Option<TimedeltaNanos> {
+        let duration = self.end.0.checked_sub(self.start.0)?;
+        TimedeltaNanos::try_from(duration).ok()
+    }
+}
+
+/// An iterator adaptor which groups timestamped items into fixed intervals.
+///
+/// Each item is assigned to the bucket starting at its timestamp floored to `interval_ns` (see
+/// [`UnixNanos::floor_to`]), and consecutive items sharing a bucket are yielded together as
+/// `(bucket_start, items)`. Intervals containing no items are skipped rather than yielded empty.
+///
+/// The input is expected to be sorted by timestamp; an item belonging to an earlier bucket than
+/// its predecessor starts a new bucket rather than being merged. A zero `interval_ns` groups
+/// items by exact timestamp.
+pub struct BucketByInterval<I: Iterator> {
+    iter: std::iter::Peekable<I>,
+    interval_ns: DurationNanos,
+}
+
+impl<I, T> BucketByInterval<I>
+where
+    I: Iterator<Item = (UnixNanos, T)>,
+{
+    /// Creates a new [`BucketByInterval`] instance over the time-sorted `iter`.
+    pub fn new(iter: impl IntoIterator<IntoIter = I>, interval_ns: DurationNanos) -> Self {
+        Self {
+            iter: iter.into_iter().peekable(),
+            interval_ns,
+        }
+    }
+}
+
+impl<I, T> Iterator for BucketByInterval<I>
+where
+    I: Iterator<Item = (UnixNanos, T)>,
+{
+    type Item = (UnixNanos, Vec<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (ts, item) = self.iter.next()?;
+        let bucket_start = ts.floor_to(self.interval_ns);
+        let mut items = vec![item];
+        while let Some((_, item)) = self
+            .iter
+            .next_if(|(ts, _)| ts.floor_to(self.interval_ns) == bucket_start)
+        {
+            items.push(item);
+        }
+        Some((bucket_start, items))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::num::IntErrorKind;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_new() {
+        let nanos = UnixNanos::from(123);
+        assert_eq!(nanos.as_u64(), 123);
+        assert_eq!(nanos.as_i64(), Some(123));
+    }
+
+    #[rstest]
+    fn test_default() {
+        let nanos = UnixNanos::default();
+        assert_eq!(nanos.as_u64(), 0);
+        assert_eq!(nanos.as_i64(), Some(0));
+    }
+
+    #[rstest]
+    fn test_constants() {
+        assert_eq!(UnixNanos::ZERO, 0);
+        assert_eq!(UnixNanos::MIN, 0);
+        assert_eq!(UnixNanos::MAX, u64::MAX);
+        assert_eq!(UnixNanos::ZERO, UnixNanos::default());
+    }
+
+    #[rstest]
+    fn test_is_zero() {
+        assert!(UnixNanos::ZERO.is_zero());
+        assert!(!UnixNanos::from(1).is_zero());
+        assert!(!UnixNanos::MAX.is_zero());
+    }
+
+    #[rstest]
+    fn test_from_duration_since_epoch() {
+        let nanos = UnixNanos::from_duration_since_epoch(Duration::new(1_700_000_000, 123));
+        assert_eq!(nanos, 1_700_000_000_000_000_123);
+    }
+
+    #[rstest]
+    fn test_from_duration_since_epoch_saturates() {
+        let nanos = UnixNanos::from_duration_since_epoch(Duration::MAX);
+        assert_eq!(nanos, UnixNanos::MAX);
+    }
+
+    #[rstest]
+    fn test_duration_since() {
+        let earlier = UnixNanos::from_secs(1_700_000_000);
+        let later = UnixNanos::from_secs(1_700_000_001);
+        assert_eq!(later.duration_since(earlier), Some(Duration::from_secs(1)));
+        assert_eq!(later.duration_since(later), Some(Duration::ZERO));
+        assert_eq!(earlier.duration_since(later), None);
+    }
+
+    #[rstest]
+    fn test_now() {
+        let recent = UnixNanos::from(1_700_000_000_000_000_000); // 2023-11-14
+        let now = UnixNanos::now();
+        assert!(now > recent);
+        assert!(UnixNanos::now() >= now);
+    }
+
+    #[rstest]
+    fn test_from_secs() {
+        let nanos = UnixNanos::from_secs(1_700_000_000);
+        assert_eq!(nanos.as_u64(), 1_700_000_000_000_000_000);
+        assert_eq!(nanos.as_secs(), 1_700_000_000);
+    }
+
+    #[rstest]
+    fn test_from_millis() {
+        let nanos = UnixNanos::from_millis(1_700_000_000_123);
+        assert_eq!(nanos.as_u64(), 1_700_000_000_123_000_000);
+        assert_eq!(nanos.as_millis(), 1_700_000_000_123);
+    }
+
+    #[rstest]
+    fn test_from_micros() {
+        let nanos = UnixNanos::from_micros(1_700_000_000_123_456);
+        assert_eq!(nanos.as_u64(), 1_700_000_000_123_456_000);
+        assert_eq!(nanos.as_micros(), 1_700_000_000_123_456);
+    }
+
+    #[rstest]
+    fn test_as_units_truncate() {
+        let nanos = UnixNanos::from(1_999_999_999);
+        assert_eq!(nanos.as_secs(), 1);
+        assert_eq!(nanos.as_millis(), 1_999);
+        assert_eq!(nanos.as_micros(), 1_999_999);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid `secs` 18446744074, exceeded maximum 18446744073")]
+    fn test_from_secs_overflow() {
+        let _ = UnixNanos::from_secs(u64::MAX / 1_000_000_000 + 1);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid `millis`")]
+    fn test_from_millis_overflow() {
+        let _ = UnixNanos::from_millis(MAX_MILLIS + 1);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid `micros`")]
+    fn test_from_micros_overflow() {
+        let _ = UnixNanos::from_micros(MAX_MICROS + 1);
+    }
+
+    #[rstest]
+    fn test_try_from_secs_at_boundary() {
+        let nanos = UnixNanos::try_from_secs(MAX_SECS).unwrap();
+        assert_eq!(nanos.as_secs(), MAX_SECS);
+        assert!(UnixNanos::try_from_secs(MAX_SECS + 1).is_err());
+    }
+
+    #[rstest]
+    fn test_try_from_millis_at_boundary() {
+        let nanos = UnixNanos::try_from_millis(MAX_MILLIS).unwrap();
+        assert_eq!(nanos.as_millis(), MAX_MILLIS);
+        assert!(UnixNanos::try_from_millis(MAX_MILLIS + 1).is_err());
+    }
+
+    #[rstest]
+    fn test_try_from_micros_at_boundary() {
+        let nanos = UnixNanos::try_from_micros(MAX_MICROS).unwrap();
+        assert_eq!(nanos.as_micros(), MAX_MICROS);
+        assert!(UnixNanos::try_from_micros(MAX_MICROS + 1).is_err());
+    }
+
+    #[rstest]
+    #[case(0, 0, 0)]
+    #[case(1_700_000_000_123_456_789, 1_700_000_000, 123_456_789)]
+    #[case(999_999_999, 0, 999_999_999)]
+    #[case(u64::MAX, MAX_SECS, 709_551_615)]
+    fn test_secs_nanos_round_trip(#[case] value: u64, #[case] secs: u64, #[case] nanos: u32) {
+        let ts = UnixNanos::from(value);
+        assert_eq!(ts.to_secs_nanos(), (secs, nanos));
+        assert_eq!(UnixNanos::from_secs_nanos(secs, nanos), ts);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "invalid u64 for 'nanos' not in range [0, 999999999]")]
+    fn test_from_secs_nanos_invalid_nanos() {
+        let _ = UnixNanos::from_secs_nanos(1, 1_000_000_000);
+    }
+
+    #[rstest]
+    fn test_from_epoch_offset() {
+        // Vendor epoch of 2000-01-01T00:00:00Z
+        let epoch_offset_ns = 946_684_800 * 1_000_000_000;
+        let value = 86_400 * 1_000_000_000; // One day after the vendor epoch
+
+        let nanos = UnixNanos::from_epoch_offset(value, epoch_offset_ns);
+
+        assert_eq!(nanos.as_u64(), 946_771_200_000_000_000);
+        assert_eq!(nanos.to_rfc3339(), "2000-01-02T00:00:00.000000000Z");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Error applying epoch offset with overflow")]
+    fn test_from_epoch_offset_overflow() {
+        let _ = UnixNanos::from_epoch_offset(u64::MAX, 1);
+    }
+
+    #[rstest]
+    fn test_into_from() {
+        let nanos: UnixNanos = 456.into();
+        let value: u64 = nanos.into();
+        assert_eq!(value, 456);
+    }
+
+    #[rstest]
+    fn test_from_str() {
+        let nanos: UnixNanos = "123".parse().unwrap();
+        assert_eq!(nanos.as_u64(), 123);
+    }
+
+    #[rstest]
+    fn test_from_str_invalid() {
+        let result = "abc".parse::<UnixNanos>();
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case("", IntErrorKind::Empty)]
+    #[case("12a", IntErrorKind::InvalidDigit)]
+    #[case("-1", IntErrorKind::InvalidDigit)]
+    #[case("18446744073709551616", IntErrorKind::PosOverflow)]
+    fn test_from_str_error_kind(#[case] value: &str, #[case] expected: IntErrorKind) {
+        let err = value.parse::<UnixNanos>().unwrap_err();
+        assert_eq!(err.kind(), &expected);
+    }
+
+    #[rstest]
+    fn test_from_str_max() {
+        let nanos: UnixNanos = "18446744073709551615".parse().unwrap();
+        assert_eq!(nanos, UnixNanos::MAX);
+    }
+
+    #[rstest]
+    fn test_try_from_datetime_valid() {
+        use chrono::TimeZone;
+        let datetime = Utc.timestamp_opt(1_000_000_000, 0).unwrap(); // 1 billion seconds since epoch
+        let nanos = UnixNanos::from(datetime);
+        assert_eq!(nanos.as_u64(), 1_000_000_000_000_000_000);
+        assert_eq!(UnixNanos::try_from_datetime(datetime).unwrap(), nanos);
+    }
+
+    #[rstest]
+    fn test_try_from_datetime_pre_epoch() {
+        use chrono::TimeZone;
+        let datetime = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap();
+        assert!(UnixNanos::try_from_datetime(datetime).is_err());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Condition failed")]
+    fn test_from_datetime_pre_epoch() {
+        use chrono::TimeZone;
+        let datetime = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap();
+        let _ = UnixNanos::from(datetime);
+    }
+
+    #[rstest]
+    fn test_to_datetime_utc() {
+        use chrono::TimeZone;
+        let nanos = UnixNanos::from(1_700_000_000_000_000_001);
+        let datetime = nanos.to_datetime_utc();
+        let expected = Utc.with_ymd_and_hms(2023, 11, 14, 22, 13, 20).unwrap()
+            + chrono::Duration::nanoseconds(1);
+        assert_eq!(datetime, expected);
+        assert_eq!(DateTime::<Utc>::from(nanos), expected);
+        assert_eq!(UnixNanos::from(datetime), nanos);
+    }
+
+    #[rstest]
+    #[case(2023, 11, 14, 22, 13, 20)]
+    #[case(2024, 2, 29, 0, 0, 0)] // Leap day, already at midnight
+    #[case(2024, 3, 31, 1, 30, 0)] // European DST transition date
+    #[case(1970, 1, 1, 23, 59, 59)]
+    fn test_start_of_utc_day(
+        #[case] year: i32,
+        #[case] month: u32,
+        #[case] day: u32,
+        #[case] hour: u32,
+        #[case] min: u32,
+        #[case] sec: u32,
+    ) {
+        use chrono::TimeZone;
+        let datetime = Utc
+            .with_ymd_and_hms(year, month, day, hour, min, sec)
+            .unwrap()
+            + chrono::Duration::nanoseconds(123);
+        let result = UnixNanos::from(datetime).start_of_utc_day();
+        let expected = Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap();
+        assert_eq!(result.to_datetime_utc(), expected);
+    }
+
+    #[rstest]
+    #[case((2023, 11, 14, 22), (2023, 11, 13))] // Tuesday
+    #[case((2023, 11, 13, 0), (2023, 11, 13))] // Monday midnight
+    #[case((2023, 11, 19, 23), (2023, 11, 13))] // Sunday
+    #[case((2024, 1, 2, 12), (2024, 1, 1))] // Across year boundary
+    #[case((1970, 1, 5, 12), (1970, 1, 5))] // First Monday after the epoch
+    fn test_start_of_utc_week(#[case] ts: (i32, u32, u32, u32), #[case] expected: (i32, u32, u32)) {
+        use chrono::{Datelike, TimeZone, Weekday};
+        let datetime = Utc.with_ymd_and_hms(ts.0, ts.1, ts.2, ts.3, 30, 0).unwrap();
+        let result = UnixNanos::from(datetime)
+            .start_of_utc_week()
+            .to_datetime_utc();
+        let expected = Utc
+            .with_ymd_and_hms(expected.0, expected.1, expected.2, 0, 0, 0)
+            .unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(result.weekday(), Weekday::Mon);
+    }
+
+    #[rstest]
+    fn test_start_of_utc_week_before_first_monday_saturates() {
+        let nanos = UnixNanos::from_secs(3 * 86_400); // Sunday 1970-01-04
+        assert_eq!(nanos.start_of_utc_week(), UnixNanos::ZERO);
+    }
+
+    #[rstest]
+    fn test_to_datetime_utc_max() {
+        let datetime = UnixNanos::from(u64::MAX).to_datetime_utc();
+        assert_eq!(datetime.timestamp(), (u64::MAX / 1_000_000_000) as i64);
+    }
+
+    #[rstest]
+    #[case(1_700_000_000_000_000_000, "2023-11-14T22:13:20.000000000Z")]
+    #[case(1_700_000_000_123_456_789, "2023-11-14T22:13:20.123456789Z")]
+    #[case(1_700_000_000_000_000_100, "2023-11-14T22:13:20.000000100Z")]
+    fn test_to_rfc3339(#[case] value: u64, #[case] expected: &str) {
+        let nanos = UnixNanos::from(value);
+        assert_eq!(nanos.to_rfc3339(), expected);
+        assert_eq!(UnixNanos::from_rfc3339(expected).unwrap(), nanos);
+    }
+
+    #[rstest]
+    fn test_from_rfc3339_with_offset() {
+        let nanos = UnixNanos::from_rfc3339("2023-11-15T08:13:20.000000100+10:00").unwrap();
+        assert_eq!(nanos.as_u64(), 1_700_000_000_000_000_100);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("2023-11-14 22:13:20")]
+    #[case("1969-12-31T23:59:59Z")]
+    fn test_from_rfc3339_invalid(#[case] value: &str) {
+        assert!(UnixNanos::from_rfc3339(value).is_err());
+    }
+
+    #[rstest]
+    fn test_eq() {
+        let nanos = UnixNanos::from(100);
+        assert_eq!(nanos, 100);
+        assert_eq!(nanos, Some(100));
+        assert_ne!(nanos, 200);
+        assert_ne!(nanos, Some(200));
+        assert_ne!(nanos, None);
+    }
+
+    #[rstest]
+    fn test_partial_cmp() {
+        let nanos = UnixNanos::from(100);
+        assert_eq!(nanos.partial_cmp(&100), Some(Ordering::Equal));
+        assert_eq!(nanos.partial_cmp(&200), Some(Ordering::Less));
+        assert_eq!(nanos.partial_cmp(&50), Some(Ordering::Greater));
+        assert_eq!(nanos.partial_cmp(&None), Some(Ordering::Greater));
     }
 
     #[rstest]
@@ -278,12 +1451,248 @@ mod tests {
         assert_eq!(format!("{nanos}"), format!("{}", u64::MAX));
     }
 
+    #[rstest]
+    fn test_add_negative_timedelta() {
+        let nanos = UnixNanos::from(1_000);
+        let result = nanos + Timedelta::new(-300);
+        assert_eq!(result, UnixNanos::from(700));
+        assert!(result < nanos);
+    }
+
+    #[rstest]
+    fn test_add_sub_timedelta() {
+        let nanos = UnixNanos::from(1_000);
+        assert_eq!(nanos + Timedelta::new(300), UnixNanos::from(1_300));
+        assert_eq!(nanos - Timedelta::new(300), UnixNanos::from(700));
+        assert_eq!(nanos - Timedelta::new(-300), UnixNanos::from(1_300));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Error subtracting with underflow")]
+    fn test_sub_timedelta_underflow() {
+        let _ = UnixNanos::from(100) - Timedelta::new(101);
+    }
+
+    #[rstest]
+    fn test_checked_and_saturating_timedelta() {
+        let nanos = UnixNanos::from(100);
+        assert_eq!(nanos.checked_add_timedelta(Timedelta::new(-101)), None);
+        assert_eq!(
+            nanos.checked_sub_timedelta(Timedelta::new(50)),
+            Some(UnixNanos::from(50))
+        );
+        assert_eq!(
+            nanos.saturating_add_timedelta(Timedelta::MIN),
+            UnixNanos::ZERO
+        );
+        assert_eq!(
+            UnixNanos::MAX.saturating_sub_timedelta(Timedelta::MIN),
+            UnixNanos::MAX
+        );
+    }
+
+    #[rstest]
+    fn test_timedelta_arithmetic() {
+        let a = Timedelta::new(100);
+        let b = Timedelta::from(-250);
+        assert_eq!(a + b, Timedelta::new(-150));
+        assert_eq!(a - b, Timedelta::new(350));
+        assert_eq!(-b, Timedelta::new(250));
+        assert!(b.is_negative());
+        assert_eq!(i64::from(b), -250);
+        assert_eq!(b.to_string(), "-250");
+        assert_eq!(Timedelta::MAX.checked_add(a), None);
+        assert_eq!(Timedelta::MIN.checked_sub(a), None);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Error negating with overflow")]
+    fn test_timedelta_neg_overflow() {
+        let _ = -Timedelta::MIN;
+    }
+
+    #[rstest]
+    fn test_sum() {
+        let values = [100, 200, 300].map(UnixNanos::from);
+        assert_eq!(UnixNanos::sum(values), Some(UnixNanos::from(600)));
+    }
+
+    #[rstest]
+    fn test_sum_empty() {
+        assert_eq!(UnixNanos::sum(Vec::new()), Some(UnixNanos::ZERO));
+    }
+
+    #[rstest]
+    fn test_sum_overflow() {
+        let values = vec![UnixNanos::MAX, UnixNanos::from(1), UnixNanos::from(2)];
+        assert_eq!(UnixNanos::sum(values), None);
+    }
+
+    #[rstest]
+    fn test_step_range_even() {
+        let result: Vec<u64> =
+            UnixNanos::step_range(UnixNanos::from(100), UnixNanos::from(400), 100)
+                .map(|t| t.as_u64())
+                .collect();
+        assert_eq!(result, vec![100, 200, 300]);
+    }
+
+    #[rstest]
+    fn test_step_range_uneven_tail() {
+        let result: Vec<u64> =
+            UnixNanos::step_range(UnixNanos::from(100), UnixNanos::from(450), 100)
+                .map(|t| t.as_u64())
+                .collect();
+        assert_eq!(result, vec![100, 200, 300, 400]);
+    }
+
+    #[rstest]
+    #[case(100, 400, 0)] // Zero step
+    #[case(400, 400, 100)] // Empty range
+    #[case(500, 400, 100)] // Reversed range
+    fn test_step_range_empty(#[case] start: u64, #[case] end: u64, #[case] step: u64) {
+        let mut range = UnixNanos::step_range(UnixNanos::from(start), UnixNanos::from(end), step);
+        assert_eq!(range.next(), None);
+    }
+
+    #[rstest]
+    fn test_step_range_near_max_does_not_overflow() {
+        let start = UnixNanos::from(u64::MAX - 10);
+        let result: Vec<UnixNanos> = UnixNanos::step_range(start, UnixNanos::MAX, 8).collect();
+        assert_eq!(result, vec![start, UnixNanos::from(u64::MAX - 2)]);
+    }
+
+    #[rstest]
+    #[case(99, false)]
+    #[case(100, true)]
+    #[case(150, true)]
+    #[case(200, true)]
+    #[case(201, false)]
+    fn test_nanos_range_closed_contains(#[case] ts: u64, #[case] expected: bool) {
+        let range = NanosRange::closed(UnixNanos::from(100), UnixNanos::from(200));
+        assert!(range.inclusive_end);
+        assert_eq!(range.contains(UnixNanos::from(ts)), expected);
+    }
+
+    #[rstest]
+    #[case(99, false)]
+    #[case(100, true)]
+    #[case(199, true)]
+    #[case(200, false)]
+    fn test_nanos_range_half_open_contains(#[case] ts: u64, #[case] expected: bool) {
+        let range = NanosRange::half_open(UnixNanos::from(100), UnixNanos::from(200));
+        assert!(!range.inclusive_end);
+        assert_eq!(range.contains(UnixNanos::from(ts)), expected);
+    }
+
+    #[rstest]
+    fn test_nanos_range_empty_half_open() {
+        let range = NanosRange::half_open(UnixNanos::from(100), UnixNanos::from(100));
+        assert!(!range.contains(UnixNanos::from(100)));
+        assert_eq!(range.duration(), Some(0));
+    }
+
+    #[rstest]
+    #[case(100, 200, Some(100))]
+    #[case(100, 100, Some(0))]
+    #[case(200, 100, None)] // Inverted
+    #[case(0, u64::MAX, None)] // Exceeds `TimedeltaNanos`
+    fn test_nanos_range_duration(
+        #[case] start: u64,
+        #[case] end: u64,
+        #[case] expected: Option<TimedeltaNanos>,
+    ) {
+        let range = NanosRange::closed(UnixNanos::from(start), UnixNanos::from(end));
+        assert_eq!(range.duration(), expected);
+    }
+
+    #[rstest]
+    fn test_nanos_range_inverted_contains_nothing() {
+        let range = NanosRange::closed(UnixNanos::from(200), UnixNanos::from(100));
+        assert!(!range.contains(UnixNanos::from(150)));
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(123, 123)]
+    #[case(i64::MAX, i64::MAX as u64)]
+    fn test_try_from_i64(#[case] value: i64, #[case] expected: u64) {
+        let nanos = UnixNanos::try_from(value).unwrap();
+        assert_eq!(nanos.as_u64(), expected);
+        assert_eq!(nanos.as_i64(), Some(value));
+    }
+
+    #[rstest]
+    #[case(-1)]
+    #[case(i64::MIN)]
+    fn test_try_from_i64_negative(#[case] value: i64) {
+        let result = UnixNanos::try_from(value);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Invalid `UnixNanos` from negative value {value}")
+        );
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(1_700_000_000_123_456_789)]
+    #[case(u64::MAX)]
+    fn test_system_time_round_trip(#[case] value: u64) {
+        let system_time = UNIX_EPOCH + Duration::from_nanos(value);
+        let nanos = UnixNanos::try_from(system_time).unwrap();
+        assert_eq!(nanos.as_u64(), value);
+        assert_eq!(SystemTime::try_from(nanos).unwrap(), system_time);
+    }
+
+    #[rstest]
+    fn test_try_from_system_time_pre_epoch() {
+        let system_time = UNIX_EPOCH - Duration::from_secs(1);
+        assert!(UnixNanos::try_from(system_time).is_err());
+    }
+
+    #[rstest]
+    #[case(i64::MAX as u64 + 1)]
+    #[case(u64::MAX)]
+    fn test_as_i64_above_i64_max(#[case] value: u64) {
+        assert_eq!(UnixNanos::from(value).as_i64(), None);
+    }
+
     #[rstest]
     fn test_display() {
         let nanos = UnixNanos::from(123);
         assert_eq!(format!("{nanos}"), "123");
     }
 
+    #[rstest]
+    #[case(0)]
+    #[case(123)]
+    #[case(1_705_322_096_789_012_345)]
+    #[case(u64::MAX)]
+    fn test_write_to_matches_display(#[case] value: u64) {
+        let nanos = UnixNanos::from(value);
+        let mut buf = String::from("ts=");
+        nanos.write_to(&mut buf).unwrap();
+        assert_eq!(buf, format!("ts={nanos}"));
+        assert_eq!(buf, format!("ts={value}"));
+    }
+
+    #[rstest]
+    fn test_display_alternate() {
+        let nanos = UnixNanos::from(1_705_322_096_789_012_345);
+        assert_eq!(format!("{nanos}"), "1705322096789012345");
+        assert_eq!(format!("{nanos:#}"), "2024-01-15T12:34:56.789012345Z");
+        assert_eq!(nanos.to_human(), "2024-01-15T12:34:56.789012345Z");
+    }
+
+    #[rstest]
+    fn test_to_human_epoch() {
+        assert_eq!(
+            UnixNanos::default().to_human(),
+            "1970-01-01T00:00:00.000000000Z"
+        );
+    }
+
     #[rstest]
     fn test_addition() {
         let nanos1 = UnixNanos::from(100);
@@ -340,6 +1749,290 @@ mod tests {
         let _ = UnixNanos::default() - 1_u64; // This should panic due to underflow
     }
 
+    #[rstest]
+    fn test_checked_add() {
+        let nanos = UnixNanos::from(100);
+        assert_eq!(
+            nanos.checked_add(UnixNanos::from(50)),
+            Some(UnixNanos::from(150))
+        );
+        assert_eq!(
+            UnixNanos::from(u64::MAX - 1).checked_add(UnixNanos::from(1)),
+            Some(UnixNanos::from(u64::MAX))
+        );
+        assert_eq!(
+            UnixNanos::from(u64::MAX).checked_add(UnixNanos::from(1)),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_checked_sub() {
+        let nanos = UnixNanos::from(100);
+        assert_eq!(
+            nanos.checked_sub(UnixNanos::from(100)),
+            Some(UnixNanos::default())
+        );
+        assert_eq!(nanos.checked_sub(UnixNanos::from(101)), None);
+        assert_eq!(UnixNanos::default().checked_sub(UnixNanos::from(1)), None);
+    }
+
+    #[rstest]
+    #[case(100, 50, Some(150))]
+    #[case(100, -50, Some(50))]
+    #[case(100, -100, Some(0))]
+    #[case(100, -101, None)]
+    #[case(u64::MAX, 1, None)]
+    #[case(u64::MAX, 0, Some(u64::MAX))]
+    #[case(0, i64::MIN, None)]
+    fn test_checked_add_i64(
+        #[case] value: u64,
+        #[case] delta: TimedeltaNanos,
+        #[case] expected: Option<u64>,
+    ) {
+        let result = UnixNanos::from(value).checked_add_i64(delta);
+        assert_eq!(result, expected.map(UnixNanos::from));
+    }
+
+    #[rstest]
+    #[case(100, 50, Some(50))]
+    #[case(100, -50, Some(150))]
+    #[case(0, 1, None)]
+    #[case(u64::MAX, -1, None)]
+    #[case(u64::MAX, i64::MAX, Some(u64::MAX - i64::MAX as u64))]
+    fn test_checked_sub_i64(
+        #[case] value: u64,
+        #[case] delta: TimedeltaNanos,
+        #[case] expected: Option<u64>,
+    ) {
+        let result = UnixNanos::from(value).checked_sub_i64(delta);
+        assert_eq!(result, expected.map(UnixNanos::from));
+    }
+
+    #[rstest]
+    #[case((100, 1), (100, 2), Ordering::Less)]
+    #[case((100, 2), (100, 1), Ordering::Greater)]
+    #[case((100, 1), (100, 1), Ordering::Equal)]
+    #[case((99, 5), (100, 1), Ordering::Less)] // Timestamp takes precedence over sequence
+    #[case((101, 0), (100, 9), Ordering::Greater)]
+    fn test_cmp_with_seq(#[case] a: (u64, u64), #[case] b: (u64, u64), #[case] expected: Ordering) {
+        let a = (UnixNanos::from(a.0), a.1);
+        let b = (UnixNanos::from(b.0), b.1);
+        assert_eq!(cmp_with_seq(a, b), expected);
+    }
+
+    #[rstest]
+    fn test_cmp_with_seq_sort_equal_timestamps() {
+        let ts = UnixNanos::from(1_000);
+        let mut events = vec![(ts, 3), (UnixNanos::from(500), 7), (ts, 1), (ts, 2)];
+
+        events.sort_by(|a, b| cmp_with_seq(*a, *b));
+
+        assert_eq!(
+            events,
+            vec![(UnixNanos::from(500), 7), (ts, 1), (ts, 2), (ts, 3)]
+        );
+    }
+
+    #[rstest]
+    #[case(1_500, 1_000, 1_000, 2_000)]
+    #[case(1_000, 1_000, 1_000, 1_000)] // On boundary
+    #[case(0, 1_000, 0, 0)]
+    #[case(999, 1_000, 0, 1_000)]
+    #[case(1_500, 0, 1_500, 1_500)] // Zero interval
+    #[case(u64::MAX, 1, u64::MAX, u64::MAX)]
+    fn test_floor_to_ceil_to(
+        #[case] value: u64,
+        #[case] interval_ns: u64,
+        #[case] expected_floor: u64,
+        #[case] expected_ceil: u64,
+    ) {
+        let nanos = UnixNanos::from(value);
+        assert_eq!(nanos.floor_to(interval_ns), expected_floor);
+        assert_eq!(nanos.ceil_to(interval_ns), expected_ceil);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Error aligning with overflow")]
+    fn test_ceil_to_overflow() {
+        let _ = UnixNanos::MAX.ceil_to(1_000);
+    }
+
+    #[rstest]
+    fn test_min_max() {
+        let earlier = UnixNanos::from(100);
+        let later = UnixNanos::from(200);
+        assert_eq!(earlier.min(later), earlier);
+        assert_eq!(later.min(earlier), earlier);
+        assert_eq!(earlier.max(later), later);
+        assert_eq!(later.max(earlier), later);
+    }
+
+    #[rstest]
+    #[case(50, 100)] // Below window
+    #[case(100, 100)]
+    #[case(150, 150)] // Within window
+    #[case(200, 200)]
+    #[case(250, 200)] // Above window
+    fn test_clamp(#[case] value: u64, #[case] expected: u64) {
+        let result = UnixNanos::from(value).clamp(UnixNanos::from(100), UnixNanos::from(200));
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid window")]
+    fn test_clamp_inverted_window() {
+        let _ = UnixNanos::from(150).clamp(UnixNanos::from(200), UnixNanos::from(100));
+    }
+
+    #[rstest]
+    fn test_saturating_add() {
+        let nanos = UnixNanos::from(100);
+        assert_eq!(nanos.saturating_add(UnixNanos::from(50)), 150);
+        assert_eq!(
+            UnixNanos::from(u64::MAX).saturating_add(UnixNanos::from(1)),
+            u64::MAX
+        );
+    }
+
+    #[rstest]
+    fn test_saturating_sub() {
+        let nanos = UnixNanos::from(100);
+        assert_eq!(nanos.saturating_sub(UnixNanos::from(50)), 50);
+        assert_eq!(
+            nanos.saturating_sub(UnixNanos::from(101)),
+            UnixNanos::default()
+        );
+        assert_eq!(
+            UnixNanos::default().saturating_sub(UnixNanos::from(1)),
+            UnixNanos::default()
+        );
+    }
+
+    #[rstest]
+    #[case(100, 50, 150)]
+    #[case(100, -50, 50)]
+    #[case(100, -101, 0)]
+    #[case(u64::MAX, 1, u64::MAX)]
+    #[case(0, i64::MIN, 0)]
+    fn test_saturating_add_i64(
+        #[case] value: u64,
+        #[case] delta: TimedeltaNanos,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(UnixNanos::from(value).saturating_add_i64(delta), expected);
+    }
+
+    #[rstest]
+    #[case(100, 50, 50)]
+    #[case(100, -50, 150)]
+    #[case(100, 101, 0)]
+    #[case(0, i64::MAX, 0)]
+    #[case(u64::MAX, -1, u64::MAX)]
+    fn test_saturating_sub_i64(
+        #[case] value: u64,
+        #[case] delta: TimedeltaNanos,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(UnixNanos::from(value).saturating_sub_i64(delta), expected);
+    }
+
+    #[rstest]
+    #[case(1_000, 1.5, 1_500)]
+    #[case(1_000, 0.0, 0)]
+    #[case(3, 0.5, 2)]
+    #[case(1_000, -1.0, 0)]
+    #[case(u64::MAX, 2.0, u64::MAX)]
+    #[case(1_000, f64::NAN, 0)]
+    fn test_scale_duration(#[case] value: u64, #[case] factor: f64, #[case] expected: u64) {
+        assert_eq!(UnixNanos::from(value).scale_duration(factor), expected);
+    }
+
+    #[rstest]
+    fn test_diff() {
+        let earlier = UnixNanos::from(100);
+        let later = UnixNanos::from(250);
+        assert_eq!(later.diff(earlier), 150);
+        assert_eq!(earlier.diff(later), -150);
+        assert_eq!(earlier.diff(earlier), 0);
+    }
+
+    #[rstest]
+    fn test_diff_saturates() {
+        let max = UnixNanos::from(u64::MAX);
+        let zero = UnixNanos::default();
+        assert_eq!(max.diff(zero), i64::MAX);
+        assert_eq!(zero.diff(max), i64::MIN);
+    }
+
+    #[rstest]
+    fn test_as_u64_in_const_context() {
+        const fn day_offset_ns(ts: UnixNanos) -> u64 {
+            ts.as_u64() - ts.start_of_utc_day().as_u64()
+        }
+
+        let ts = UnixNanos::from(NANOSECONDS_IN_DAY + 1_500);
+        assert_eq!(day_offset_ns(ts), 1_500);
+        assert_eq!(ts.as_u64(), *ts);
+    }
+
+    #[rstest]
+    #[case(1_000_000_000, 1_000_250_000, 250_000)]
+    #[case(1_000_250_000, 1_000_000_000, -250_000)] // Event stamped ahead of local clock
+    #[case(1_000_000_000, 1_000_000_000, 0)]
+    #[case(0, u64::MAX, i64::MAX)] // Saturates
+    #[case(u64::MAX, 0, i64::MIN)] // Saturates
+    fn test_elapsed_since(#[case] event_ts: u64, #[case] now: u64, #[case] expected: i64) {
+        let now = UnixNanos::from(now);
+        assert_eq!(now.elapsed_since(UnixNanos::from(event_ts)), expected);
+    }
+
+    #[rstest]
+    fn test_bucket_by_interval() {
+        let events = vec![
+            (UnixNanos::from(1_000), 'a'),
+            (UnixNanos::from(1_999), 'b'),
+            (UnixNanos::from(2_000), 'c'),
+            (UnixNanos::from(2_500), 'd'),
+            // Gap with no events in [3_000, 5_000)
+            (UnixNanos::from(5_001), 'e'),
+        ];
+
+        let buckets: Vec<_> = BucketByInterval::new(events, 1_000).collect();
+
+        assert_eq!(
+            buckets,
+            vec![
+                (UnixNanos::from(1_000), vec!['a', 'b']),
+                (UnixNanos::from(2_000), vec!['c', 'd']),
+                (UnixNanos::from(5_000), vec!['e']),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_bucket_by_interval_empty() {
+        let events: Vec<(UnixNanos, u8)> = Vec::new();
+        assert_eq!(BucketByInterval::new(events, 1_000).next(), None);
+    }
+
+    #[rstest]
+    fn test_checked_diff() {
+        let max = UnixNanos::from(u64::MAX);
+        let zero = UnixNanos::default();
+        assert_eq!(
+            UnixNanos::from(100).checked_diff(UnixNanos::from(250)),
+            Some(-150)
+        );
+        assert_eq!(
+            UnixNanos::from(i64::MAX as u64).checked_diff(zero),
+            Some(i64::MAX)
+        );
+        assert_eq!(max.checked_diff(zero), None);
+        assert_eq!(zero.checked_diff(max), None);
+    }
+
     #[rstest]
     fn test_serde_json() {
         let nanos = UnixNanos::from(123);
@@ -348,6 +2041,40 @@ mod tests {
         assert_eq!(deserialized, nanos);
     }
 
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct StrNanos {
+        #[serde(with = "serde_as_str")]
+        ts: UnixNanos,
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1_700_000_000_123_456_789)]
+    #[case(u64::MAX)]
+    fn test_serde_as_str_round_trip(#[case] value: u64) {
+        let original = StrNanos {
+            ts: UnixNanos::from(value),
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(json, format!(r#"{{"ts":"{value}"}}"#));
+        let deserialized: StrNanos = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[rstest]
+    fn test_serde_as_str_from_integer() {
+        let deserialized: StrNanos = serde_json::from_str(r#"{"ts":1700000000123456789}"#).unwrap();
+        assert_eq!(deserialized.ts, 1_700_000_000_123_456_789);
+    }
+
+    #[rstest]
+    #[case(r#"{"ts":"abc"}"#)]
+    #[case(r#"{"ts":-1}"#)]
+    #[case(r#"{"ts":"18446744073709551616"}"#)]
+    fn test_serde_as_str_invalid(#[case] json: &str) {
+        assert!(serde_json::from_str::<StrNanos>(json).is_err());
+    }
+
     #[rstest]
     fn test_serde_edge_cases() {
         let nanos = UnixNanos::from(u64::MAX);
@@ -499,6 +499,14 @@ impl<'r> FromRow<'r, PgRow> for CryptoPerpetualModel {
         let taker_fee = row
             .try_get::<String, _>("taker_fee")
             .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let funding_rate = row
+            .try_get::<Option<String>, _>("funding_rate")
+            .ok()
+            .and_then(|res| res.map(|res| Decimal::from_str(res.as_str()).unwrap()));
+        let funding_interval_ns = row
+            .try_get::<Option<String>, _>("funding_interval_ns")
+            .ok()
+            .and_then(|res| res.map(UnixNanos::from));
         let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
         let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
         let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
@@ -526,6 +534,8 @@ impl<'r> FromRow<'r, PgRow> for CryptoPerpetualModel {
             margin_maint,
             maker_fee,
             taker_fee,
+            funding_rate,
+            funding_interval_ns,
             ts_event,
             ts_init,
         );
@@ -48,6 +48,7 @@ mod serial_tests {
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
         types::{Currency, Price, Quantity},
     };
+    use rust_decimal::Decimal;
     use serde::Serialize;
     use ustr::Ustr;
 
@@ -471,6 +472,39 @@ mod serial_tests {
         pg_cache.close().unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_postgres_cache_database_add_crypto_perpetual_with_funding() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let mut crypto_perpetual = crypto_perpetual_ethusdt();
+        crypto_perpetual.funding_rate = Some(Decimal::new(1, 4));
+        crypto_perpetual.funding_interval_ns = Some(UnixNanos::from_secs(8 * 60 * 60));
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual);
+        pg_cache
+            .add_currency(&instrument.base_currency().unwrap())
+            .unwrap();
+        pg_cache.add_currency(&instrument.quote_currency()).unwrap();
+        pg_cache.add_instrument(&instrument).unwrap();
+        wait_until(
+            || {
+                pg_cache
+                    .load_instrument(&instrument.id())
+                    .unwrap()
+                    .is_some()
+            },
+            Duration::from_secs(2),
+        );
+        let loaded = pg_cache.load_instrument(&instrument.id()).unwrap().unwrap();
+        assert_eq!(loaded.funding_rate(), Some(Decimal::new(1, 4)));
+        assert_eq!(
+            loaded.funding_interval_ns(),
+            Some(UnixNanos::from_secs(8 * 60 * 60))
+        );
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_postgres_cache_database_add_quote_tick() {
         let mut pg_cache = get_pg_cache_database().await.unwrap();
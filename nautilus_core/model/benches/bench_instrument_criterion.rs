@@ -0,0 +1,50 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, Criterion};
+use nautilus_model::{
+    identifiers::InstrumentId,
+    instruments::{CryptoPerpetual, Instrument},
+    types::{Currency, Price},
+};
+
+pub fn bench_make_prices(c: &mut Criterion) {
+    let instrument = CryptoPerpetual::with_defaults(
+        InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+        Currency::ETH(),
+        Currency::USDT(),
+    );
+    let values: Vec<f64> = (0..10_000)
+        .map(|i| 2000.0 + f64::from(i) * 0.0037)
+        .collect();
+    let mut out: Vec<Price> = Vec::with_capacity(values.len());
+
+    c.bench_function("make_price", |b| {
+        b.iter(|| {
+            out.clear();
+            out.extend(values.iter().map(|v| instrument.make_price(black_box(*v))));
+        });
+    });
+
+    c.bench_function("make_prices", |b| {
+        b.iter(|| {
+            out.clear();
+            instrument.make_prices(black_box(&values), &mut out);
+        });
+    });
+}
+
+criterion_group!(benches, bench_make_prices);
+criterion::criterion_main!(benches);
@@ -24,7 +24,7 @@ use super::{
     options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
 };
 use crate::{
-    enums::InstrumentClass,
+    enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol, Venue},
     types::{Currency, Money, Price, Quantity},
 };
@@ -61,6 +61,15 @@ impl InstrumentAny {
         }
     }
 
+    /// Returns a reference to the underlying [`CryptoPerpetual`], if this is that variant.
+    #[must_use]
+    pub fn as_crypto_perpetual(&self) -> Option<&CryptoPerpetual> {
+        match self {
+            Self::CryptoPerpetual(inst) => Some(inst),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub fn instrument_class(&self) -> InstrumentClass {
         match self {
@@ -514,8 +523,270 @@ impl InstrumentAny {
     }
 }
 
+/// Forwards a call to the [`Instrument`] implementation of the inner variant.
+macro_rules! forward_to_variant {
+    ($self:ident, $method:ident) => {
+        match $self {
+            InstrumentAny::Betting(inst) => Instrument::$method(inst),
+            InstrumentAny::BinaryOption(inst) => Instrument::$method(inst),
+            InstrumentAny::CryptoFuture(inst) => Instrument::$method(inst),
+            InstrumentAny::CryptoPerpetual(inst) => Instrument::$method(inst),
+            InstrumentAny::CurrencyPair(inst) => Instrument::$method(inst),
+            InstrumentAny::Equity(inst) => Instrument::$method(inst),
+            InstrumentAny::FuturesContract(inst) => Instrument::$method(inst),
+            InstrumentAny::FuturesSpread(inst) => Instrument::$method(inst),
+            InstrumentAny::OptionsContract(inst) => Instrument::$method(inst),
+            InstrumentAny::OptionsSpread(inst) => Instrument::$method(inst),
+        }
+    };
+}
+
+/// Forwards every [`Instrument`] method to the inner variant, so generic code can call the
+/// trait methods (e.g. `any.price_increment()`) without matching on the variant.
+///
+/// The provided trait methods which variants override (margins, fees, funding and the
+/// settlement type) are forwarded explicitly, while the other provided methods are computed
+/// from the forwarded accessors.
+/// Where `InstrumentAny` also has an inherent method of the same name, method call syntax
+/// resolves to the inherent method.
+impl Instrument for InstrumentAny {
+    fn into_any(self) -> InstrumentAny {
+        self
+    }
+
+    fn id(&self) -> InstrumentId {
+        forward_to_variant!(self, id)
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        forward_to_variant!(self, raw_symbol)
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        forward_to_variant!(self, asset_class)
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        forward_to_variant!(self, instrument_class)
+    }
+
+    fn underlying(&self) -> Option<Ustr> {
+        forward_to_variant!(self, underlying)
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        forward_to_variant!(self, base_currency)
+    }
+
+    fn quote_currency(&self) -> Currency {
+        forward_to_variant!(self, quote_currency)
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        forward_to_variant!(self, settlement_currency)
+    }
+
+    fn isin(&self) -> Option<Ustr> {
+        forward_to_variant!(self, isin)
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        forward_to_variant!(self, option_kind)
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        forward_to_variant!(self, exchange)
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        forward_to_variant!(self, strike_price)
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        forward_to_variant!(self, activation_ns)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        forward_to_variant!(self, expiration_ns)
+    }
+
+    fn is_inverse(&self) -> bool {
+        forward_to_variant!(self, is_inverse)
+    }
+
+    fn price_precision(&self) -> u8 {
+        forward_to_variant!(self, price_precision)
+    }
+
+    fn size_precision(&self) -> u8 {
+        forward_to_variant!(self, size_precision)
+    }
+
+    fn price_increment(&self) -> Price {
+        forward_to_variant!(self, price_increment)
+    }
+
+    fn size_increment(&self) -> Quantity {
+        forward_to_variant!(self, size_increment)
+    }
+
+    fn multiplier(&self) -> Quantity {
+        forward_to_variant!(self, multiplier)
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        forward_to_variant!(self, lot_size)
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        forward_to_variant!(self, max_quantity)
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        forward_to_variant!(self, min_quantity)
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        forward_to_variant!(self, max_notional)
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        forward_to_variant!(self, min_notional)
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        forward_to_variant!(self, max_price)
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        forward_to_variant!(self, min_price)
+    }
+
+    fn margin_init(&self) -> Decimal {
+        forward_to_variant!(self, margin_init)
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        forward_to_variant!(self, margin_maint)
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        forward_to_variant!(self, maker_fee)
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        forward_to_variant!(self, taker_fee)
+    }
+
+    fn funding_rate(&self) -> Option<Decimal> {
+        forward_to_variant!(self, funding_rate)
+    }
+
+    fn funding_interval_ns(&self) -> Option<UnixNanos> {
+        forward_to_variant!(self, funding_interval_ns)
+    }
+
+    fn is_cash_settled(&self) -> bool {
+        forward_to_variant!(self, is_cash_settled)
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        forward_to_variant!(self, ts_event)
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        forward_to_variant!(self, ts_init)
+    }
+}
+
 impl PartialEq for InstrumentAny {
     fn eq(&self, other: &Self) -> bool {
         self.id() == other.id()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        enums::{AssetClass, InstrumentClass, PositionSide},
+        instruments::{
+            stubs::*, BettingInstrument, BinaryOption, CryptoFuture, CryptoPerpetual, Instrument,
+            InstrumentAny,
+        },
+        types::{Money, Price, Quantity},
+    };
+
+    /// Calls the trait methods through a generic bound, bypassing the inherent methods.
+    fn price_increment_of<I: Instrument>(instrument: &I) -> Price {
+        instrument.price_increment()
+    }
+
+    #[rstest]
+    fn test_forwards_accessors(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let any = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+
+        assert_eq!(Instrument::id(&any), crypto_perpetual_ethusdt.id);
+        assert_eq!(any.asset_class(), AssetClass::Cryptocurrency);
+        assert_eq!(Instrument::instrument_class(&any), InstrumentClass::Swap);
+        assert_eq!(price_increment_of(&any), Price::from("0.01"));
+        assert_eq!(any.lot_size(), crypto_perpetual_ethusdt.lot_size());
+        assert_eq!(any.max_price(), Some(Price::from("15000.00")));
+        assert_eq!(any.venue_str(), "BINANCE");
+    }
+
+    #[rstest]
+    fn test_forwards_overridden_provided_methods(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let any = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+
+        assert_eq!(any.margin_init(), dec!(1.0));
+        assert_eq!(any.margin_maint(), dec!(0.35));
+        assert_eq!(Instrument::maker_fee(&any), dec!(0.0002));
+        assert_eq!(Instrument::taker_fee(&any), dec!(0.0004));
+        assert_eq!(any.funding_rate(), crypto_perpetual_ethusdt.funding_rate);
+    }
+
+    #[rstest]
+    fn test_forwards_is_cash_settled(
+        crypto_future_btcusdt: CryptoFuture,
+        betting: BettingInstrument,
+        binary_option: BinaryOption,
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        for any in [
+            InstrumentAny::CryptoFuture(crypto_future_btcusdt),
+            InstrumentAny::Betting(betting),
+            InstrumentAny::BinaryOption(binary_option),
+            InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt),
+        ] {
+            assert!(any.is_cash_settled());
+            assert!(!any.is_physically_settled());
+        }
+
+        let any = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+        assert!(!any.is_cash_settled());
+    }
+
+    #[rstest]
+    fn test_provided_methods_match_variant(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let any = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let quantity = Quantity::from("1.000");
+        let price = Price::from("2000.00");
+
+        assert_eq!(
+            any.calculate_margin_init(quantity, price),
+            crypto_perpetual_ethusdt.calculate_margin_init(quantity, price)
+        );
+        assert_eq!(
+            any.calculate_pnl(price, Price::from("2100.00"), quantity, PositionSide::Long),
+            Money::from("100.00 USDT")
+        );
+        assert_eq!(any.validate_order(quantity, price), Ok(()));
+        assert_eq!(any.clone().into_any(), any);
+    }
+}
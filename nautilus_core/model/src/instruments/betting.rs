@@ -357,6 +357,10 @@ impl Instrument for BettingInstrument {
         false
     }
 
+    fn is_cash_settled(&self) -> bool {
+        true
+    }
+
     fn price_precision(&self) -> u8 {
         self.price_precision
     }
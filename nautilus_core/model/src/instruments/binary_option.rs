@@ -290,6 +290,10 @@ impl Instrument for BinaryOption {
         false
     }
 
+    fn is_cash_settled(&self) -> bool {
+        true
+    }
+
     fn price_precision(&self) -> u8 {
         self.price_precision
     }
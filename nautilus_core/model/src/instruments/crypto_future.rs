@@ -302,6 +302,10 @@ impl Instrument for CryptoFuture {
         self.is_inverse
     }
 
+    fn is_cash_settled(&self) -> bool {
+        true
+    }
+
     fn price_precision(&self) -> u8 {
         self.price_precision
     }
@@ -378,11 +382,39 @@ impl Instrument for CryptoFuture {
 mod tests {
     use rstest::rstest;
 
-    use crate::instruments::{stubs::*, CryptoFuture};
+    use crate::instruments::{stubs::*, CryptoFuture, Instrument};
 
     #[rstest]
     fn test_equality(crypto_future_btcusdt: CryptoFuture) {
         let cloned = crypto_future_btcusdt;
         assert_eq!(crypto_future_btcusdt, cloned);
     }
+
+    #[rstest]
+    fn test_is_tradable(crypto_future_btcusdt: CryptoFuture) {
+        let activation_ns = crypto_future_btcusdt.activation_ns;
+        let expiration_ns = crypto_future_btcusdt.expiration_ns;
+
+        assert!(!crypto_future_btcusdt.is_tradable(activation_ns - 1)); // Activates in future
+        assert!(crypto_future_btcusdt.is_tradable(activation_ns));
+        assert!(crypto_future_btcusdt.is_tradable(expiration_ns - 1));
+        assert!(!crypto_future_btcusdt.is_tradable(expiration_ns)); // Expired
+    }
+
+    #[rstest]
+    fn test_is_expired(crypto_future_btcusdt: CryptoFuture) {
+        let expiration_ns = crypto_future_btcusdt.expiration_ns;
+
+        assert!(!crypto_future_btcusdt.is_expired(crypto_future_btcusdt.activation_ns));
+        assert!(!crypto_future_btcusdt.is_expired(expiration_ns - 1));
+        assert!(crypto_future_btcusdt.is_expired(expiration_ns));
+        assert!(crypto_future_btcusdt.is_expired(expiration_ns + 1));
+    }
+
+    #[rstest]
+    fn test_is_cash_settled(crypto_future_btcusdt: CryptoFuture) {
+        assert!(!crypto_future_btcusdt.is_inverse);
+        assert!(crypto_future_btcusdt.is_cash_settled());
+        assert!(!crypto_future_btcusdt.is_physically_settled());
+    }
 }
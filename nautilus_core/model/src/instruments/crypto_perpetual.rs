@@ -13,13 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::hash::{Hash, Hasher};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 use nautilus_core::{
-    correctness::{check_equal_u8, check_positive_i64, check_positive_u64, FAILED},
-    nanos::UnixNanos,
+    correctness::FAILED,
+    nanos::{TimedeltaNanos, UnixNanos},
+    serialization::Serializable,
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -27,13 +33,29 @@ use super::any::InstrumentAny;
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{InstrumentId, Symbol},
-    instruments::Instrument,
-    types::{Currency, Money, Price, Quantity},
+    instruments::{Instrument, InstrumentError},
+    types::{
+        fixed::FIXED_PRECISION, round_money, BasisPoints, Currency, CurrencyResolver, Money, Price,
+        Quantity, PRICE_MAX, PRICE_MIN, QUANTITY_MAX, QUANTITY_MIN,
+    },
 };
 
 /// Represents a crypto perpetual futures contract instrument (a.k.a. perpetual swap).
+///
+/// # Serialization
+///
+/// Serialized snapshots must remain readable as the struct evolves. Any field added after the
+/// initial schema is marked `#[serde(default)]`, so a snapshot written before the field existed
+/// deserializes with the default value (`None` for optional fields). Fields are never renamed
+/// or removed without a `#[serde(alias)]` for the previous name.
+///
+/// The `price_precision` and `size_precision` fields are not serialized, as they are derived
+/// from the precision of the respective increment on deserialization. Snapshots which include
+/// them are still accepted, provided they match the increment precisions. Every snapshot is
+/// validated by [`CryptoPerpetual::new_checked`].
 #[repr(C)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "CryptoPerpetualSnapshot")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -53,8 +75,10 @@ pub struct CryptoPerpetual {
     /// If the instrument costing is inverse (quantity expressed in quote currency units).
     pub is_inverse: bool,
     /// The price decimal precision.
+    #[serde(skip_serializing)]
     pub price_precision: u8,
     /// The trading size decimal precision.
+    #[serde(skip_serializing)]
     pub size_precision: u8,
     /// The minimum price increment (tick size).
     pub price_increment: Price,
@@ -65,13 +89,23 @@ pub struct CryptoPerpetual {
     /// The rounded lot unit size (standard/board).
     pub lot_size: Quantity,
     /// The initial (order) margin requirement in percentage of order value.
+    #[serde(with = "rust_decimal::serde::str")]
     pub margin_init: Decimal,
     /// The maintenance (position) margin in percentage of position value.
+    #[serde(with = "rust_decimal::serde::str")]
     pub margin_maint: Decimal,
     /// The fee rate for liquidity makers as a percentage of order value.
+    #[serde(with = "rust_decimal::serde::str")]
     pub maker_fee: Decimal,
     /// The fee rate for liquidity takers as a percentage of order value.
+    #[serde(with = "rust_decimal::serde::str")]
     pub taker_fee: Decimal,
+    /// The current funding rate as a percentage of position value per funding interval.
+    #[serde(default)]
+    pub funding_rate: Option<Decimal>,
+    /// The funding interval (nanoseconds) between funding payments.
+    #[serde(default)]
+    pub funding_interval_ns: Option<UnixNanos>,
     /// The maximum allowable order quantity.
     pub max_quantity: Option<Quantity>,
     /// The minimum allowable order quantity.
@@ -93,6 +127,19 @@ pub struct CryptoPerpetual {
 impl CryptoPerpetual {
     /// Creates a new [`CryptoPerpetual`] instance with correctness checking.
     ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - [`InstrumentError::PrecisionMismatch`] if `price_precision` or `size_precision` does
+    ///   not match the precision of the respective increment.
+    /// - [`InstrumentError::NonPositiveIncrement`] if `price_increment` or `size_increment` is
+    ///   not positive.
+    /// - [`InstrumentError::NonPositiveValue`] if a present `multiplier` or
+    ///   `funding_interval_ns` is not positive.
+    /// - [`InstrumentError::InvalidLimit`] if a present `max_quantity` is zero, or a present
+    ///   `max_notional` or `min_notional` is negative.
+    /// - [`InstrumentError::InvertedLimits`] if a minimum limit is greater than its maximum.
+    ///
     /// # Notes
     ///
     /// PyO3 requires a `Result` type for proper error handling and stacktrace printing in Python.
@@ -120,23 +167,101 @@ impl CryptoPerpetual {
         margin_maint: Option<Decimal>,
         maker_fee: Option<Decimal>,
         taker_fee: Option<Decimal>,
+        funding_rate: Option<Decimal>,
+        funding_interval_ns: Option<UnixNanos>,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
-    ) -> anyhow::Result<Self> {
-        check_equal_u8(
-            price_precision,
-            price_increment.precision,
-            stringify!(price_precision),
-            stringify!(price_increment.precision),
-        )?;
-        check_equal_u8(
-            size_precision,
-            size_increment.precision,
-            stringify!(size_precision),
-            stringify!(size_increment.precision),
-        )?;
-        check_positive_i64(price_increment.raw, stringify!(price_increment.raw))?;
-        check_positive_u64(size_increment.raw, stringify!(size_increment.raw))?;
+    ) -> Result<Self, InstrumentError> {
+        if price_precision != price_increment.precision {
+            return Err(InstrumentError::PrecisionMismatch {
+                instrument_id: id,
+                field: stringify!(price_precision),
+                precision: price_precision,
+                increment_field: stringify!(price_increment),
+                increment_precision: price_increment.precision,
+            });
+        }
+        if size_precision != size_increment.precision {
+            return Err(InstrumentError::PrecisionMismatch {
+                instrument_id: id,
+                field: stringify!(size_precision),
+                precision: size_precision,
+                increment_field: stringify!(size_increment),
+                increment_precision: size_increment.precision,
+            });
+        }
+        if price_increment.raw <= 0 {
+            return Err(InstrumentError::NonPositiveIncrement {
+                instrument_id: id,
+                field: stringify!(price_increment),
+                value: price_increment.to_string(),
+            });
+        }
+        if size_increment.raw == 0 {
+            return Err(InstrumentError::NonPositiveIncrement {
+                instrument_id: id,
+                field: stringify!(size_increment),
+                value: size_increment.to_string(),
+            });
+        }
+        if let Some(multiplier) = multiplier.filter(|m| m.raw == 0) {
+            return Err(InstrumentError::NonPositiveValue {
+                instrument_id: id,
+                field: stringify!(multiplier),
+                value: multiplier.to_string(),
+            });
+        }
+        if let Some(interval) = funding_interval_ns.filter(|i| i.is_zero()) {
+            return Err(InstrumentError::NonPositiveValue {
+                instrument_id: id,
+                field: stringify!(funding_interval_ns),
+                value: interval.to_string(),
+            });
+        }
+        if let Some(max_quantity) = max_quantity.filter(|q| q.raw == 0) {
+            return Err(InstrumentError::InvalidLimit {
+                instrument_id: id,
+                field: stringify!(max_quantity),
+                value: max_quantity.to_string(),
+            });
+        }
+        for (field, limit) in [
+            (stringify!(max_notional), max_notional),
+            (stringify!(min_notional), min_notional),
+        ] {
+            if let Some(limit) = limit.filter(|m| m.raw < 0) {
+                return Err(InstrumentError::InvalidLimit {
+                    instrument_id: id,
+                    field,
+                    value: limit.to_string(),
+                });
+            }
+        }
+        if let (Some(min), Some(max)) = (min_quantity, max_quantity) {
+            if min > max {
+                return Err(InstrumentError::InvertedLimits {
+                    instrument_id: id,
+                    field: "quantity",
+                });
+            }
+        }
+        if let (Some(min), Some(max)) = (min_price, max_price) {
+            if min > max {
+                return Err(InstrumentError::InvertedLimits {
+                    instrument_id: id,
+                    field: "price",
+                });
+            }
+        }
+        // Limits in different currencies cannot be compared
+        if let (Some(min), Some(max)) = (min_notional, max_notional) {
+            if min.currency == max.currency && min > max {
+                return Err(InstrumentError::InvertedLimits {
+                    instrument_id: id,
+                    field: "notional",
+                });
+            }
+        }
 
         Ok(Self {
             id,
@@ -151,10 +276,13 @@ impl CryptoPerpetual {
             size_increment,
             multiplier: multiplier.unwrap_or(Quantity::from(1)),
             lot_size: lot_size.unwrap_or(Quantity::from(1)),
-            margin_init: margin_init.unwrap_or_default(),
-            margin_maint: margin_maint.unwrap_or_default(),
-            maker_fee: maker_fee.unwrap_or_default(),
-            taker_fee: taker_fee.unwrap_or_default(),
+            // Normalize so that equal values with different scales compare and serialize equally
+            margin_init: margin_init.unwrap_or_default().normalize(),
+            margin_maint: margin_maint.unwrap_or_default().normalize(),
+            maker_fee: maker_fee.unwrap_or_default().normalize(),
+            taker_fee: taker_fee.unwrap_or_default().normalize(),
+            funding_rate: funding_rate.map(|rate| rate.normalize()),
+            funding_interval_ns,
             max_quantity,
             min_quantity,
             max_notional,
@@ -166,6 +294,86 @@ impl CryptoPerpetual {
         })
     }
 
+    /// Creates a new [`CryptoPerpetual`] instance with strict correctness checking.
+    ///
+    /// In addition to the checks performed by [`CryptoPerpetual::new_checked`] (which permits
+    /// any settlement currency, such as a third currency), this requires the
+    /// `settlement_currency` to equal either the `base_currency` or the `quote_currency`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any check performed by [`CryptoPerpetual::new_checked`] fails.
+    /// - [`InstrumentError::InvalidSettlementCurrency`] if `settlement_currency` is neither the
+    ///   `base_currency` nor the `quote_currency`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked_strict(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        is_inverse: bool,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        multiplier: Option<Quantity>,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        funding_rate: Option<Decimal>,
+        funding_interval_ns: Option<UnixNanos>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self, InstrumentError> {
+        if settlement_currency != base_currency && settlement_currency != quote_currency {
+            return Err(InstrumentError::InvalidSettlementCurrency {
+                instrument_id: id,
+                settlement_currency,
+                base_currency,
+                quote_currency,
+            });
+        }
+
+        Self::new_checked(
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            funding_rate,
+            funding_interval_ns,
+            ts_event,
+            ts_init,
+        )
+    }
+
     /// Creates a new [`CryptoPerpetual`] instance
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -191,6 +399,8 @@ impl CryptoPerpetual {
         margin_maint: Option<Decimal>,
         maker_fee: Option<Decimal>,
         taker_fee: Option<Decimal>,
+        funding_rate: Option<Decimal>,
+        funding_interval_ns: Option<UnixNanos>,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
     ) -> Self {
@@ -217,11 +427,648 @@ impl CryptoPerpetual {
             margin_maint,
             maker_fee,
             taker_fee,
+            funding_rate,
+            funding_interval_ns,
             ts_event,
             ts_init,
         )
         .expect(FAILED)
     }
+
+    /// Creates a new linear [`CryptoPerpetual`] instance for `id` with default parameters.
+    ///
+    /// The price precision is taken from the `quote` currency and the size precision from the
+    /// `base` currency, with increments of one unit at each precision. The instrument settles in
+    /// the `quote` currency, with zero fees and margins and no limits.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If a currency precision exceeds the maximum fixed precision.
+    #[must_use]
+    pub fn with_defaults(id: InstrumentId, base: Currency, quote: Currency) -> Self {
+        let price_precision = quote.precision;
+        let size_precision = base.precision;
+        let price_increment = Price::from_raw(
+            10_i64.pow(u32::from(FIXED_PRECISION - price_precision)),
+            price_precision,
+        );
+        let size_increment = Quantity::from_raw(
+            10_u64.pow(u32::from(FIXED_PRECISION - size_precision)),
+            size_precision,
+        );
+
+        Self::new(
+            id,
+            id.symbol,
+            base,
+            quote,
+            quote,
+            false,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    /// Returns whether the `price_increment` and `size_increment` are aligned with the
+    /// `price_precision` and `size_precision` respectively.
+    ///
+    /// An increment is aligned when it is stated at the instrument's precision, and that
+    /// precision is the increment's natural precision (the fewest decimals which represent it
+    /// exactly). For example, a `price_increment` of `0.100` with a `price_precision` of 3 is
+    /// misaligned, as the increment's natural precision is 1.
+    #[must_use]
+    pub fn is_increment_aligned(&self) -> bool {
+        self.price_increment.precision == self.price_precision
+            && self.size_increment.precision == self.size_precision
+            && natural_precision(self.price_increment.raw.unsigned_abs()) == self.price_precision
+            && natural_precision(self.size_increment.raw) == self.size_precision
+    }
+
+    /// Normalizes the `price_precision` and `size_precision` (and the precision of the
+    /// respective increments) to the natural precision of each increment, so that the
+    /// instrument is aligned as per [`CryptoPerpetual::is_increment_aligned`].
+    ///
+    /// The `max_price`, `min_price`, `max_quantity` and `min_quantity` limits are re-expressed
+    /// at the normalized precisions, with their values unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InstrumentError::InvalidLimit`] if a limit is not representable at the
+    /// normalized precision (e.g. a `min_price` of `1.005` with a `price_increment` of `0.100`),
+    /// in which case the instrument is left unchanged.
+    pub fn normalize(&mut self) -> Result<(), InstrumentError> {
+        let price_precision = natural_precision(self.price_increment.raw.unsigned_abs());
+        let size_precision = natural_precision(self.size_increment.raw);
+
+        let price = |limit: Option<Price>, field| -> Result<Option<Price>, InstrumentError> {
+            limit
+                .map(|limit| {
+                    if natural_precision(limit.raw.unsigned_abs()) > price_precision {
+                        return Err(InstrumentError::InvalidLimit {
+                            instrument_id: self.id,
+                            field,
+                            value: limit.to_string(),
+                        });
+                    }
+                    Ok(Price::from_raw(limit.raw, price_precision))
+                })
+                .transpose()
+        };
+        let quantity =
+            |limit: Option<Quantity>, field| -> Result<Option<Quantity>, InstrumentError> {
+                limit
+                    .map(|limit| {
+                        if natural_precision(limit.raw) > size_precision {
+                            return Err(InstrumentError::InvalidLimit {
+                                instrument_id: self.id,
+                                field,
+                                value: limit.to_string(),
+                            });
+                        }
+                        Ok(Quantity::from_raw(limit.raw, size_precision))
+                    })
+                    .transpose()
+            };
+
+        let max_price = price(self.max_price, stringify!(max_price))?;
+        let min_price = price(self.min_price, stringify!(min_price))?;
+        let max_quantity = quantity(self.max_quantity, stringify!(max_quantity))?;
+        let min_quantity = quantity(self.min_quantity, stringify!(min_quantity))?;
+
+        self.price_precision = price_precision;
+        self.price_increment = Price::from_raw(self.price_increment.raw, price_precision);
+        self.size_precision = size_precision;
+        self.size_increment = Quantity::from_raw(self.size_increment.raw, size_precision);
+        self.max_price = max_price;
+        self.min_price = min_price;
+        self.max_quantity = max_quantity;
+        self.min_quantity = min_quantity;
+        Ok(())
+    }
+
+    /// Validates the precisions of all the given `instruments` against their increments.
+    ///
+    /// Unlike the constructors, which fail on the first invalid field, this checks every
+    /// instrument (such as a statically defined catalog) and reports every mismatch at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InstrumentError::PrecisionMismatch`] for each instrument where the
+    /// `price_precision` or `size_precision` does not match the precision of the respective
+    /// increment.
+    pub fn validate_all(instruments: &[Self]) -> Result<(), Vec<InstrumentError>> {
+        let mut errors = Vec::new();
+        for instrument in instruments {
+            if instrument.price_precision != instrument.price_increment.precision {
+                errors.push(InstrumentError::PrecisionMismatch {
+                    instrument_id: instrument.id,
+                    field: stringify!(price_precision),
+                    precision: instrument.price_precision,
+                    increment_field: stringify!(price_increment),
+                    increment_precision: instrument.price_increment.precision,
+                });
+            }
+            if instrument.size_precision != instrument.size_increment.precision {
+                errors.push(InstrumentError::PrecisionMismatch {
+                    instrument_id: instrument.id,
+                    field: stringify!(size_precision),
+                    precision: instrument.size_precision,
+                    increment_field: stringify!(size_increment),
+                    increment_precision: instrument.size_increment.precision,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns the funding accrued on a position of `position_notional` over the `elapsed`
+    /// nanoseconds, prorated from the funding rate per funding interval.
+    ///
+    /// The funding is `funding_rate * position_notional * (elapsed / funding_interval_ns)`,
+    /// calculated with `Decimal` and returned in the currency of `position_notional`. Returns
+    /// `None` if either the `funding_rate` or `funding_interval_ns` is not set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the accrued funding is outside the representable range for `Money`.
+    #[must_use]
+    pub fn accrued_funding(
+        &self,
+        position_notional: Money,
+        elapsed: TimedeltaNanos,
+    ) -> Option<Money> {
+        let funding_rate = self.funding_rate?;
+        let interval = Decimal::from(self.funding_interval_ns?.as_u64());
+        let accrued =
+            funding_rate * position_notional.as_decimal() * Decimal::from(elapsed) / interval;
+        Some(round_money(accrued, position_notional.currency))
+    }
+
+    /// Returns a copy of the instrument with the quantity and notional limits multiplied by
+    /// `factor`, for migrating the instrument when the venue changes the contract size.
+    ///
+    /// The `max_quantity`, `min_quantity`, `lot_size`, `max_notional` and `min_notional` are
+    /// rescaled (where present) and the result is validated by re-running
+    /// [`CryptoPerpetual::new_checked`]. The prices, increments and all other fields are
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `factor` is not positive.
+    /// - If a rescaled quantity or notional is not representable at its precision (without
+    ///   rounding).
+    /// - If a rescaled quantity or notional is outside the representable range for its type.
+    /// - If the rescaled instrument fails validation.
+    pub fn rescale_limits(&self, factor: Decimal) -> anyhow::Result<Self> {
+        if factor <= Decimal::ZERO {
+            anyhow::bail!("Invalid `factor` {factor}, was not positive");
+        }
+
+        let quantity = |value: Option<Quantity>, field| -> anyhow::Result<Option<Quantity>> {
+            value
+                .map(|value| {
+                    let scaled = rescale_exact(value.as_decimal(), factor, value.precision, field)?;
+                    if scaled > Decimal::from(QUANTITY_MAX as u64) {
+                        anyhow::bail!("Invalid `{field}` {scaled}, exceeded {QUANTITY_MAX}");
+                    }
+                    let raw = scaled * Decimal::from(10_u64.pow(u32::from(FIXED_PRECISION)));
+                    let raw = raw.to_u64().expect("Invalid raw value for quantity");
+                    Ok(Quantity::from_raw(raw, value.precision))
+                })
+                .transpose()
+        };
+        let notional = |value: Option<Money>, field| -> anyhow::Result<Option<Money>> {
+            value
+                .map(|value| {
+                    let precision = value.currency.precision;
+                    let scaled = rescale_exact(value.as_decimal(), factor, precision, field)?;
+                    // Exact at the currency precision, so only the range is checked here
+                    Money::from_decimal(scaled, value.currency)
+                        .map_err(|e| anyhow::anyhow!("Invalid `{field}` {scaled}: {e}"))
+                })
+                .transpose()
+        };
+
+        let instrument = Self::new_checked(
+            self.id,
+            self.raw_symbol,
+            self.base_currency,
+            self.quote_currency,
+            self.settlement_currency,
+            self.is_inverse,
+            self.price_precision,
+            self.size_precision,
+            self.price_increment,
+            self.size_increment,
+            Some(self.multiplier),
+            quantity(Some(self.lot_size), "lot_size")?,
+            quantity(self.max_quantity, "max_quantity")?,
+            quantity(self.min_quantity, "min_quantity")?,
+            notional(self.max_notional, "max_notional")?,
+            notional(self.min_notional, "min_notional")?,
+            self.max_price,
+            self.min_price,
+            Some(self.margin_init),
+            Some(self.margin_maint),
+            Some(self.maker_fee),
+            Some(self.taker_fee),
+            self.funding_rate,
+            self.funding_interval_ns,
+            self.ts_event,
+            self.ts_init,
+        )?;
+        Ok(instrument)
+    }
+
+    /// Returns a stable hash of the instrument specification, for detecting spec changes
+    /// (such as fees or limits) between versions of the same instrument.
+    ///
+    /// All fields other than the `ts_event` and `ts_init` timestamps are hashed with a stable
+    /// (FNV-1a) hasher, so the value may be persisted and compared across processes. This is
+    /// distinct from the `Hash` implementation, which only hashes the `id`.
+    #[must_use]
+    pub fn spec_hash(&self) -> u64 {
+        let mut hasher = SpecHasher::new();
+        hasher.write_str(self.id.symbol.as_str());
+        hasher.write_str(self.id.venue.as_str());
+        hasher.write_str(self.raw_symbol.as_str());
+        hasher.write_currency(self.base_currency);
+        hasher.write_currency(self.quote_currency);
+        hasher.write_currency(self.settlement_currency);
+        hasher.write(&[u8::from(self.is_inverse)]);
+        hasher.write(&[self.price_precision, self.size_precision]);
+        hasher.write_price(self.price_increment);
+        hasher.write_quantity(self.size_increment);
+        hasher.write_quantity(self.multiplier);
+        hasher.write_quantity(self.lot_size);
+        hasher.write_decimal(self.margin_init);
+        hasher.write_decimal(self.margin_maint);
+        hasher.write_decimal(self.maker_fee);
+        hasher.write_decimal(self.taker_fee);
+        hasher.write_option(self.funding_rate, SpecHasher::write_decimal);
+        hasher.write_option(self.funding_interval_ns, |h, v| {
+            h.write(&v.as_u64().to_le_bytes())
+        });
+        hasher.write_option(self.max_quantity, SpecHasher::write_quantity);
+        hasher.write_option(self.min_quantity, SpecHasher::write_quantity);
+        hasher.write_option(self.max_notional, SpecHasher::write_money);
+        hasher.write_option(self.min_notional, SpecHasher::write_money);
+        hasher.write_option(self.max_price, SpecHasher::write_price);
+        hasher.write_option(self.min_price, SpecHasher::write_price);
+        hasher.finish()
+    }
+
+    /// Returns whether all fields of this instrument are equal to those of `other`.
+    ///
+    /// Unlike `PartialEq`, which only compares the instrument ID, this detects specification
+    /// changes (such as fees or limits) between two versions of the same instrument.
+    #[must_use]
+    pub fn equals_full(&self, other: &Self) -> bool {
+        let Self {
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            lot_size,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            funding_rate,
+            funding_interval_ns,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        } = self;
+        *id == other.id
+            && *raw_symbol == other.raw_symbol
+            && *base_currency == other.base_currency
+            && *quote_currency == other.quote_currency
+            && *settlement_currency == other.settlement_currency
+            && *is_inverse == other.is_inverse
+            && *price_precision == other.price_precision
+            && *size_precision == other.size_precision
+            && *price_increment == other.price_increment
+            && *size_increment == other.size_increment
+            && *multiplier == other.multiplier
+            && *lot_size == other.lot_size
+            && *margin_init == other.margin_init
+            && *margin_maint == other.margin_maint
+            && *maker_fee == other.maker_fee
+            && *taker_fee == other.taker_fee
+            && *funding_rate == other.funding_rate
+            && *funding_interval_ns == other.funding_interval_ns
+            && *max_quantity == other.max_quantity
+            && *min_quantity == other.min_quantity
+            && *max_notional == other.max_notional
+            && *min_notional == other.min_notional
+            && *max_price == other.max_price
+            && *min_price == other.min_price
+            && *ts_event == other.ts_event
+            && *ts_init == other.ts_init
+    }
+
+    /// Returns a [`FieldDiff`] for each field which differs between this instrument and
+    /// `other`, in field declaration order, for reconciliation reports.
+    ///
+    /// The `ts_event` and `ts_init` timestamps are ignored.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<FieldDiff> {
+        let Self {
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            lot_size,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            funding_rate,
+            funding_interval_ns,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event: _,
+            ts_init: _,
+        } = self;
+
+        let mut diffs = Vec::new();
+        macro_rules! diff_field {
+            ($field:ident) => {
+                if *$field != other.$field {
+                    diffs.push(FieldDiff {
+                        name: stringify!($field),
+                        old: $field.to_string(),
+                        new: other.$field.to_string(),
+                    });
+                }
+            };
+            (optional $field:ident) => {
+                if *$field != other.$field {
+                    diffs.push(FieldDiff {
+                        name: stringify!($field),
+                        old: format_optional($field),
+                        new: format_optional(&other.$field),
+                    });
+                }
+            };
+        }
+
+        diff_field!(id);
+        diff_field!(raw_symbol);
+        diff_field!(base_currency);
+        diff_field!(quote_currency);
+        diff_field!(settlement_currency);
+        diff_field!(is_inverse);
+        diff_field!(price_precision);
+        diff_field!(size_precision);
+        diff_field!(price_increment);
+        diff_field!(size_increment);
+        diff_field!(multiplier);
+        diff_field!(lot_size);
+        diff_field!(margin_init);
+        diff_field!(margin_maint);
+        diff_field!(maker_fee);
+        diff_field!(taker_fee);
+        diff_field!(optional funding_rate);
+        diff_field!(optional funding_interval_ns);
+        diff_field!(optional max_quantity);
+        diff_field!(optional min_quantity);
+        diff_field!(optional max_notional);
+        diff_field!(optional min_notional);
+        diff_field!(optional max_price);
+        diff_field!(optional min_price);
+        diffs
+    }
+}
+
+/// Represents a single differing field between two versions of an instrument.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// The name of the differing field.
+    pub name: &'static str,
+    /// The field value of the original instrument.
+    pub old: String,
+    /// The field value of the other instrument.
+    pub new: String,
+}
+
+impl Display for FieldDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.name, self.old, self.new)
+    }
+}
+
+/// Formats an optional field value for a [`FieldDiff`], with `None` for an absent value.
+fn format_optional<T: Display>(value: &Option<T>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "None".to_string(), ToString::to_string)
+}
+
+impl Serializable for CryptoPerpetual {}
+
+/// The serialized form of a [`CryptoPerpetual`], where the precision fields are optional.
+#[derive(Deserialize)]
+struct CryptoPerpetualSnapshot {
+    id: InstrumentId,
+    raw_symbol: Symbol,
+    base_currency: Currency,
+    quote_currency: Currency,
+    settlement_currency: Currency,
+    is_inverse: bool,
+    #[serde(default)]
+    price_precision: Option<u8>,
+    #[serde(default)]
+    size_precision: Option<u8>,
+    price_increment: Price,
+    size_increment: Quantity,
+    multiplier: Quantity,
+    lot_size: Quantity,
+    #[serde(with = "rust_decimal::serde::str")]
+    margin_init: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    margin_maint: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    maker_fee: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    taker_fee: Decimal,
+    #[serde(default)]
+    funding_rate: Option<Decimal>,
+    #[serde(default)]
+    funding_interval_ns: Option<UnixNanos>,
+    max_quantity: Option<Quantity>,
+    min_quantity: Option<Quantity>,
+    max_notional: Option<Money>,
+    min_notional: Option<Money>,
+    max_price: Option<Price>,
+    min_price: Option<Price>,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+}
+
+impl TryFrom<CryptoPerpetualSnapshot> for CryptoPerpetual {
+    type Error = InstrumentError;
+
+    fn try_from(value: CryptoPerpetualSnapshot) -> Result<Self, Self::Error> {
+        let price_precision = value
+            .price_precision
+            .unwrap_or(value.price_increment.precision);
+        let size_precision = value
+            .size_precision
+            .unwrap_or(value.size_increment.precision);
+
+        Self::new_checked(
+            value.id,
+            value.raw_symbol,
+            value.base_currency,
+            value.quote_currency,
+            value.settlement_currency,
+            value.is_inverse,
+            price_precision,
+            size_precision,
+            value.price_increment,
+            value.size_increment,
+            Some(value.multiplier),
+            Some(value.lot_size),
+            value.max_quantity,
+            value.min_quantity,
+            value.max_notional,
+            value.min_notional,
+            value.max_price,
+            value.min_price,
+            Some(value.margin_init),
+            Some(value.margin_maint),
+            Some(value.maker_fee),
+            Some(value.taker_fee),
+            value.funding_rate,
+            value.funding_interval_ns,
+            value.ts_event,
+            value.ts_init,
+        )
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CryptoPerpetual {
+    /// Generates an internally consistent [`CryptoPerpetual`] which satisfies every check
+    /// performed by [`CryptoPerpetual::new_checked`].
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let base_currency = *u.choose(&[Currency::BTC(), Currency::ETH(), Currency::SOL()])?;
+        let quote_currency = *u.choose(&[Currency::USD(), Currency::USDT(), Currency::USDC()])?;
+        let is_inverse = u.arbitrary()?;
+        let settlement_currency = if is_inverse {
+            base_currency
+        } else {
+            quote_currency
+        };
+
+        let price_precision = u.int_in_range(0..=8)?;
+        let size_precision = u.int_in_range(0..=8)?;
+        let price_increment = Price::from_raw(
+            u.int_in_range(1..=100)? * 10_i64.pow(u32::from(FIXED_PRECISION - price_precision)),
+            price_precision,
+        );
+        let size_increment = Quantity::from_raw(
+            u.int_in_range(1..=100)? * 10_u64.pow(u32::from(FIXED_PRECISION - size_precision)),
+            size_precision,
+        );
+
+        let mut multiplier = None;
+        if u.arbitrary()? {
+            multiplier = Some(Quantity::from(u.int_in_range(1..=1_000_i64)?));
+        }
+        let mut funding_interval_ns = None;
+        if u.arbitrary()? {
+            let hours: u64 = u.int_in_range(1..=24)?;
+            funding_interval_ns = Some(UnixNanos::from_secs(hours * 60 * 60));
+        }
+        let mut funding_rate = None;
+        if u.arbitrary()? {
+            funding_rate = Some(Decimal::new(u.int_in_range(-10_000..=10_000)?, 6));
+        }
+
+        let id = InstrumentId::from(format!("{base_currency}{quote_currency}-PERP.SIM").as_str());
+        let ts_init = UnixNanos::from(u.int_in_range(0..=u64::MAX / 2)?);
+
+        Ok(Self::new(
+            id,
+            id.symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::new(u.int_in_range(0..=10_000)?, 4)),
+            Some(Decimal::new(u.int_in_range(0..=10_000)?, 4)),
+            Some(Decimal::new(u.int_in_range(-100..=1_000)?, 6)),
+            Some(Decimal::new(u.int_in_range(0..=1_000)?, 6)),
+            funding_rate,
+            funding_interval_ns,
+            ts_init,
+            ts_init,
+        ))
+    }
 }
 
 impl PartialEq<Self> for CryptoPerpetual {
@@ -238,6 +1085,43 @@ impl Hash for CryptoPerpetual {
     }
 }
 
+impl PartialOrd for CryptoPerpetual {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CryptoPerpetual {
+    /// Orders instruments lexicographically by the string representation of their `id`
+    /// (consistent with the ID based `PartialEq` and `Hash`).
+    fn cmp(&self, other: &Self) -> Ordering {
+        id_str_bytes(&self.id).cmp(id_str_bytes(&other.id))
+    }
+}
+
+/// Returns the bytes of the `{symbol}.{venue}` string representation of `id` without allocating.
+fn id_str_bytes(id: &InstrumentId) -> impl Iterator<Item = u8> + '_ {
+    id.symbol
+        .as_str()
+        .bytes()
+        .chain(std::iter::once(b'.'))
+        .chain(id.venue.as_str().bytes())
+}
+
+impl TryFrom<InstrumentAny> for CryptoPerpetual {
+    type Error = InstrumentError;
+
+    fn try_from(instrument: InstrumentAny) -> Result<Self, Self::Error> {
+        match instrument {
+            InstrumentAny::CryptoPerpetual(inst) => Ok(inst),
+            _ => Err(InstrumentError::InvalidVariant(
+                instrument.id(),
+                stringify!(CryptoPerpetual),
+            )),
+        }
+    }
+}
+
 impl Instrument for CryptoPerpetual {
     fn into_any(self) -> InstrumentAny {
         InstrumentAny::CryptoPerpetual(self)
@@ -316,7 +1200,7 @@ impl Instrument for CryptoPerpetual {
     }
 
     fn multiplier(&self) -> Quantity {
-        Quantity::from(1)
+        self.multiplier
     }
 
     fn lot_size(&self) -> Option<Quantity> {
@@ -363,6 +1247,14 @@ impl Instrument for CryptoPerpetual {
         self.taker_fee
     }
 
+    fn funding_rate(&self) -> Option<Decimal> {
+        self.funding_rate
+    }
+
+    fn funding_interval_ns(&self) -> Option<UnixNanos> {
+        self.funding_interval_ns
+    }
+
     fn ts_event(&self) -> UnixNanos {
         self.ts_event
     }
@@ -372,18 +1264,3179 @@ impl Instrument for CryptoPerpetual {
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////
-// Tests
-////////////////////////////////////////////////////////////////////////////////
-#[cfg(test)]
-mod tests {
-    use rstest::rstest;
+/// Returns the fewest decimal places which exactly represent the fixed-point `raw` value.
+fn natural_precision(raw: u64) -> u8 {
+    let value = Decimal::from_i128_with_scale(i128::from(raw), u32::from(FIXED_PRECISION));
+    value.normalize().scale() as u8
+}
 
-    use crate::instruments::{stubs::*, CryptoPerpetual};
+/// A stable 64-bit FNV-1a hasher for [`CryptoPerpetual::spec_hash`].
+///
+/// Unlike the standard library hashers, the output does not depend on the process or Rust
+/// version. Values are written in a canonical little-endian form.
+struct SpecHasher(u64);
 
-    #[rstest]
-    fn test_equality(crypto_perpetual_ethusdt: CryptoPerpetual) {
-        let cloned = crypto_perpetual_ethusdt;
-        assert_eq!(crypto_perpetual_ethusdt, cloned);
+impl SpecHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    const fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write(value.as_bytes());
+        self.write(&[0xff]); // Terminator to separate adjacent strings
+    }
+
+    fn write_currency(&mut self, value: Currency) {
+        self.write_str(value.code.as_str());
+    }
+
+    fn write_price(&mut self, value: Price) {
+        self.write(&value.raw.to_le_bytes());
+        self.write(&[value.precision]);
+    }
+
+    fn write_quantity(&mut self, value: Quantity) {
+        self.write(&value.raw.to_le_bytes());
+        self.write(&[value.precision]);
+    }
+
+    fn write_money(&mut self, value: Money) {
+        self.write(&value.raw.to_le_bytes());
+        self.write_currency(value.currency);
+    }
+
+    fn write_decimal(&mut self, value: Decimal) {
+        // Normalize so that equal values with different scales hash equally
+        self.write(&value.normalize().serialize());
+    }
+
+    fn write_option<T>(&mut self, value: Option<T>, write: impl FnOnce(&mut Self, T)) {
+        match value {
+            Some(value) => {
+                self.write(&[1]);
+                write(self, value);
+            }
+            None => self.write(&[0]),
+        }
+    }
+
+    const fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A venue neutral specification of a crypto perpetual, with plain string and number fields as
+/// typically found in exchange info responses.
+///
+/// Decimal values are strings to avoid float conversion (e.g. `"0.01000000"`), with trailing
+/// zeros on the increments ignored when deriving the precisions. Notional limits are amounts in
+/// the quote currency, and fees and margins are fractions (e.g. `"0.0004"`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerpetualSpec {
+    /// The instrument ID in the `SYMBOL.VENUE` format (e.g. `"ETHUSDT-PERP.BINANCE"`).
+    pub instrument_id: String,
+    /// The raw/local/native symbol assigned by the venue.
+    pub raw_symbol: String,
+    /// The base currency code.
+    pub base_currency: String,
+    /// The quote currency code.
+    pub quote_currency: String,
+    /// The settlement currency code.
+    pub settlement_currency: String,
+    /// If the instrument costing is inverse.
+    pub is_inverse: bool,
+    /// The minimum price increment (tick size).
+    pub price_increment: String,
+    /// The minimum size increment (step size).
+    pub size_increment: String,
+    /// The contract multiplier.
+    pub multiplier: Option<String>,
+    /// The rounded lot unit size.
+    pub lot_size: Option<String>,
+    /// The maximum allowable order quantity.
+    pub max_quantity: Option<String>,
+    /// The minimum allowable order quantity.
+    pub min_quantity: Option<String>,
+    /// The maximum allowable order notional value (in the quote currency).
+    pub max_notional: Option<String>,
+    /// The minimum allowable order notional value (in the quote currency).
+    pub min_notional: Option<String>,
+    /// The maximum allowable quoted price.
+    pub max_price: Option<String>,
+    /// The minimum allowable quoted price.
+    pub min_price: Option<String>,
+    /// The initial (order) margin requirement as a fraction of order value.
+    pub margin_init: Option<String>,
+    /// The maintenance (position) margin as a fraction of position value.
+    pub margin_maint: Option<String>,
+    /// The fee rate for liquidity makers as a fraction of order value.
+    pub maker_fee: Option<String>,
+    /// The fee rate for liquidity takers as a fraction of order value.
+    pub taker_fee: Option<String>,
+    /// The current funding rate as a fraction of position value per funding interval.
+    pub funding_rate: Option<String>,
+    /// The funding interval (nanoseconds) between funding payments.
+    pub funding_interval_ns: Option<u64>,
+    /// UNIX timestamp (nanoseconds) when the data event occurred.
+    pub ts_event: u64,
+    /// UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: u64,
+}
+
+impl CryptoPerpetual {
+    /// Creates a new [`CryptoPerpetual`] instance from the given `spec`, resolving the currency
+    /// codes with `currencies`.
+    ///
+    /// The price and size precisions are derived from the normalized increments, and the
+    /// limits are parsed exactly (without rounding) at these precisions before running
+    /// [`CryptoPerpetual::new_checked`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instrument ID is invalid.
+    /// - If a currency code cannot be resolved.
+    /// - If any value cannot be parsed as a decimal, or is out of range for its type.
+    /// - If a price or quantity is not representable at the instrument precision.
+    /// - If any of the checks in [`CryptoPerpetual::new_checked`] fail.
+    pub fn from_spec(spec: PerpetualSpec, currencies: &CurrencyResolver) -> anyhow::Result<Self> {
+        let id = InstrumentId::from_str(&spec.instrument_id)?;
+        let base_currency = currencies.resolve(&spec.base_currency)?;
+        let quote_currency = currencies.resolve(&spec.quote_currency)?;
+        let settlement_currency = currencies.resolve(&spec.settlement_currency)?;
+
+        let price_increment = parse_spec_decimal(&spec.price_increment, "price_increment")?;
+        let size_increment = parse_spec_decimal(&spec.size_increment, "size_increment")?;
+        let price_precision = u8::try_from(price_increment.scale())?;
+        let size_precision = u8::try_from(size_increment.scale())?;
+
+        // Parse the optional values at the instrument precisions
+        let price = |value: &Option<String>, field| -> anyhow::Result<Option<Price>> {
+            value
+                .as_deref()
+                .map(|value| {
+                    spec_decimal_to_price(parse_spec_decimal(value, field)?, price_precision, field)
+                })
+                .transpose()
+        };
+        let quantity = |value: &Option<String>, field| -> anyhow::Result<Option<Quantity>> {
+            value
+                .as_deref()
+                .map(|value| {
+                    let value = parse_spec_decimal(value, field)?;
+                    spec_decimal_to_quantity(value, size_precision, field)
+                })
+                .transpose()
+        };
+        let notional = |value: &Option<String>, field| -> anyhow::Result<Option<Money>> {
+            value
+                .as_deref()
+                .map(|value| Money::from_decimal(parse_spec_decimal(value, field)?, quote_currency))
+                .transpose()
+        };
+        let decimal = |value: &Option<String>, field| -> anyhow::Result<Option<Decimal>> {
+            value
+                .as_deref()
+                .map(|value| parse_spec_decimal(value, field))
+                .transpose()
+        };
+
+        let instrument = Self::new_checked(
+            id,
+            Symbol::new_checked(&spec.raw_symbol)?,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            spec.is_inverse,
+            price_precision,
+            size_precision,
+            spec_decimal_to_price(price_increment, price_precision, "price_increment")?,
+            spec_decimal_to_quantity(size_increment, size_precision, "size_increment")?,
+            quantity(&spec.multiplier, "multiplier")?,
+            quantity(&spec.lot_size, "lot_size")?,
+            quantity(&spec.max_quantity, "max_quantity")?,
+            quantity(&spec.min_quantity, "min_quantity")?,
+            notional(&spec.max_notional, "max_notional")?,
+            notional(&spec.min_notional, "min_notional")?,
+            price(&spec.max_price, "max_price")?,
+            price(&spec.min_price, "min_price")?,
+            decimal(&spec.margin_init, "margin_init")?,
+            decimal(&spec.margin_maint, "margin_maint")?,
+            decimal(&spec.maker_fee, "maker_fee")?,
+            decimal(&spec.taker_fee, "taker_fee")?,
+            decimal(&spec.funding_rate, "funding_rate")?,
+            spec.funding_interval_ns.map(UnixNanos::from),
+            UnixNanos::from(spec.ts_event),
+            UnixNanos::from(spec.ts_init),
+        )?;
+        Ok(instrument)
+    }
+}
+
+/// Parses the decimal string `value` of the spec `field`, normalized without trailing zeros.
+fn parse_spec_decimal(value: &str, field: &str) -> anyhow::Result<Decimal> {
+    Decimal::from_str(value.trim())
+        .map(|value| value.normalize())
+        .map_err(|e| anyhow::anyhow!("Invalid `{field}` '{value}': {e}"))
+}
+
+/// Returns `value` multiplied by `factor`, provided the result is exactly representable at the
+/// given `precision`.
+fn rescale_exact(
+    value: Decimal,
+    factor: Decimal,
+    precision: u8,
+    field: &str,
+) -> anyhow::Result<Decimal> {
+    let scaled = value
+        .checked_mul(factor)
+        .ok_or_else(|| anyhow::anyhow!("Invalid `{field}` {value} * {factor}: overflow"))?;
+    if scaled.round_dp(u32::from(precision)) != scaled {
+        anyhow::bail!("Invalid `{field}` {scaled}, not representable at precision {precision}");
+    }
+    Ok(scaled)
+}
+
+/// Returns the raw fixed-point value of `value`, provided it is exactly representable at the
+/// given `precision`.
+fn spec_decimal_to_raw(value: Decimal, precision: u8, field: &str) -> anyhow::Result<i128> {
+    if value.round_dp(u32::from(precision)) != value {
+        anyhow::bail!("Invalid `{field}` {value}, not representable at precision {precision}");
+    }
+    value
+        .checked_mul(Decimal::from(10_u64.pow(u32::from(FIXED_PRECISION))))
+        .and_then(|raw| raw.to_i128())
+        .ok_or_else(|| anyhow::anyhow!("Invalid `{field}` {value}: overflow"))
+}
+
+/// Converts the spec `value` to a `Price` at the given `precision` without rounding.
+fn spec_decimal_to_price(value: Decimal, precision: u8, field: &str) -> anyhow::Result<Price> {
+    if value < Decimal::from(PRICE_MIN as i64) || value > Decimal::from(PRICE_MAX as i64) {
+        anyhow::bail!("Invalid `{field}` {value}, outside [{PRICE_MIN}, {PRICE_MAX}]");
+    }
+    let raw = spec_decimal_to_raw(value, precision, field)?;
+    Ok(Price::from_raw(
+        i64::try_from(raw).expect("Invalid raw value for price"),
+        precision,
+    ))
+}
+
+/// Converts the spec `value` to a `Quantity` at the given `precision` without rounding.
+fn spec_decimal_to_quantity(
+    value: Decimal,
+    precision: u8,
+    field: &str,
+) -> anyhow::Result<Quantity> {
+    if value.is_sign_negative() || value > Decimal::from(QUANTITY_MAX as u64) {
+        anyhow::bail!("Invalid `{field}` {value}, outside [{QUANTITY_MIN}, {QUANTITY_MAX}]");
+    }
+    let raw = spec_decimal_to_raw(value, precision, field)?;
+    Ok(Quantity::from_raw(
+        u64::try_from(raw).expect("Invalid raw value for quantity"),
+        precision,
+    ))
+}
+
+/// Provides a builder for [`CryptoPerpetual`] instances.
+///
+/// Optional limits default to `None`, fees and margins to zero, and the multiplier and lot size
+/// to 1. The `raw_symbol` defaults to the symbol of the instrument ID.
+#[derive(Clone, Debug, Default)]
+pub struct CryptoPerpetualBuilder {
+    id: Option<InstrumentId>,
+    raw_symbol: Option<Symbol>,
+    base_currency: Option<Currency>,
+    quote_currency: Option<Currency>,
+    settlement_currency: Option<Currency>,
+    is_inverse: bool,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+    price_increment: Option<Price>,
+    size_increment: Option<Quantity>,
+    multiplier: Option<Quantity>,
+    lot_size: Option<Quantity>,
+    max_quantity: Option<Quantity>,
+    min_quantity: Option<Quantity>,
+    max_notional: Option<Money>,
+    min_notional: Option<Money>,
+    max_price: Option<Price>,
+    min_price: Option<Price>,
+    margin_init: Option<Decimal>,
+    margin_maint: Option<Decimal>,
+    maker_fee: Option<Decimal>,
+    taker_fee: Option<Decimal>,
+    funding_rate: Option<Decimal>,
+    funding_interval_ns: Option<UnixNanos>,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+}
+
+impl CryptoPerpetualBuilder {
+    /// Creates a new [`CryptoPerpetualBuilder`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the instrument ID (required).
+    pub fn id(&mut self, id: InstrumentId) -> &mut Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the raw/local/native symbol, defaulting to the symbol of the instrument ID.
+    pub fn raw_symbol(&mut self, raw_symbol: Symbol) -> &mut Self {
+        self.raw_symbol = Some(raw_symbol);
+        self
+    }
+
+    /// Sets the base currency (required).
+    pub fn base_currency(&mut self, base_currency: Currency) -> &mut Self {
+        self.base_currency = Some(base_currency);
+        self
+    }
+
+    /// Sets the quote currency (required).
+    pub fn quote_currency(&mut self, quote_currency: Currency) -> &mut Self {
+        self.quote_currency = Some(quote_currency);
+        self
+    }
+
+    /// Sets the settlement currency (required).
+    pub fn settlement_currency(&mut self, settlement_currency: Currency) -> &mut Self {
+        self.settlement_currency = Some(settlement_currency);
+        self
+    }
+
+    /// Sets whether the instrument is inverse, defaulting to `false` (linear).
+    pub fn is_inverse(&mut self, is_inverse: bool) -> &mut Self {
+        self.is_inverse = is_inverse;
+        self
+    }
+
+    /// Sets the price decimal precision (required).
+    pub fn price_precision(&mut self, price_precision: u8) -> &mut Self {
+        self.price_precision = Some(price_precision);
+        self
+    }
+
+    /// Sets the size decimal precision (required).
+    pub fn size_precision(&mut self, size_precision: u8) -> &mut Self {
+        self.size_precision = Some(size_precision);
+        self
+    }
+
+    /// Sets the minimum price increment (required).
+    pub fn price_increment(&mut self, price_increment: Price) -> &mut Self {
+        self.price_increment = Some(price_increment);
+        self
+    }
+
+    /// Sets the minimum size increment (required).
+    pub fn size_increment(&mut self, size_increment: Quantity) -> &mut Self {
+        self.size_increment = Some(size_increment);
+        self
+    }
+
+    /// Sets the contract multiplier, defaulting to 1.
+    pub fn multiplier(&mut self, multiplier: Quantity) -> &mut Self {
+        self.multiplier = Some(multiplier);
+        self
+    }
+
+    /// Sets the rounded lot unit size, defaulting to 1.
+    pub fn lot_size(&mut self, lot_size: Quantity) -> &mut Self {
+        self.lot_size = Some(lot_size);
+        self
+    }
+
+    /// Sets the maximum order quantity.
+    pub fn max_quantity(&mut self, max_quantity: Quantity) -> &mut Self {
+        self.max_quantity = Some(max_quantity);
+        self
+    }
+
+    /// Sets the minimum order quantity.
+    pub fn min_quantity(&mut self, min_quantity: Quantity) -> &mut Self {
+        self.min_quantity = Some(min_quantity);
+        self
+    }
+
+    /// Sets the maximum order notional value.
+    pub fn max_notional(&mut self, max_notional: Money) -> &mut Self {
+        self.max_notional = Some(max_notional);
+        self
+    }
+
+    /// Sets the minimum order notional value.
+    pub fn min_notional(&mut self, min_notional: Money) -> &mut Self {
+        self.min_notional = Some(min_notional);
+        self
+    }
+
+    /// Sets the maximum quote price.
+    pub fn max_price(&mut self, max_price: Price) -> &mut Self {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    /// Sets the minimum quote price.
+    pub fn min_price(&mut self, min_price: Price) -> &mut Self {
+        self.min_price = Some(min_price);
+        self
+    }
+
+    /// Sets the initial (order) margin rate, defaulting to zero.
+    pub fn margin_init(&mut self, margin_init: Decimal) -> &mut Self {
+        self.margin_init = Some(margin_init);
+        self
+    }
+
+    /// Sets the maintenance (position) margin rate, defaulting to zero.
+    pub fn margin_maint(&mut self, margin_maint: Decimal) -> &mut Self {
+        self.margin_maint = Some(margin_maint);
+        self
+    }
+
+    /// Sets the maker fee rate, defaulting to zero.
+    pub fn maker_fee(&mut self, maker_fee: Decimal) -> &mut Self {
+        self.maker_fee = Some(maker_fee);
+        self
+    }
+
+    /// Sets the taker fee rate, defaulting to zero.
+    pub fn taker_fee(&mut self, taker_fee: Decimal) -> &mut Self {
+        self.taker_fee = Some(taker_fee);
+        self
+    }
+
+    /// Sets the maker fee rate from a rate quoted in basis points, as per [`Self::maker_fee`].
+    pub fn maker_fee_bps(&mut self, maker_fee: BasisPoints) -> &mut Self {
+        self.maker_fee = Some(maker_fee.to_decimal());
+        self
+    }
+
+    /// Sets the taker fee rate from a rate quoted in basis points, as per [`Self::taker_fee`].
+    pub fn taker_fee_bps(&mut self, taker_fee: BasisPoints) -> &mut Self {
+        self.taker_fee = Some(taker_fee.to_decimal());
+        self
+    }
+
+    /// Sets the funding rate applied per funding interval.
+    pub fn funding_rate(&mut self, funding_rate: Decimal) -> &mut Self {
+        self.funding_rate = Some(funding_rate);
+        self
+    }
+
+    /// Sets the funding interval (nanoseconds).
+    pub fn funding_interval_ns(&mut self, funding_interval_ns: UnixNanos) -> &mut Self {
+        self.funding_interval_ns = Some(funding_interval_ns);
+        self
+    }
+
+    /// Sets the UNIX timestamp (nanoseconds) when the data event occurred.
+    pub fn ts_event(&mut self, ts_event: UnixNanos) -> &mut Self {
+        self.ts_event = ts_event;
+        self
+    }
+
+    /// Sets the UNIX timestamp (nanoseconds) when the instance was initialized.
+    pub fn ts_init(&mut self, ts_init: UnixNanos) -> &mut Self {
+        self.ts_init = ts_init;
+        self
+    }
+
+    /// Builds the [`CryptoPerpetual`], running the same validation as
+    /// [`CryptoPerpetual::new_checked`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any required field (`id`, currencies, precisions, increments) has not been set.
+    /// - If the resulting instrument fails validation.
+    pub fn build(&self) -> anyhow::Result<CryptoPerpetual> {
+        self.build_with(false)
+    }
+
+    /// Builds the [`CryptoPerpetual`], running the same validation as
+    /// [`CryptoPerpetual::new_checked_strict`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any required field (`id`, currencies, precisions, increments) has not been set.
+    /// - If the resulting instrument fails validation.
+    pub fn build_strict(&self) -> anyhow::Result<CryptoPerpetual> {
+        self.build_with(true)
+    }
+
+    fn build_with(&self, strict: bool) -> anyhow::Result<CryptoPerpetual> {
+        let constructor = if strict {
+            CryptoPerpetual::new_checked_strict
+        } else {
+            CryptoPerpetual::new_checked
+        };
+        let (
+            Some(id),
+            Some(base_currency),
+            Some(quote_currency),
+            Some(settlement_currency),
+            Some(price_precision),
+            Some(size_precision),
+            Some(price_increment),
+            Some(size_increment),
+        ) = (
+            self.id,
+            self.base_currency,
+            self.quote_currency,
+            self.settlement_currency,
+            self.price_precision,
+            self.size_precision,
+            self.price_increment,
+            self.size_increment,
+        )
+        else {
+            anyhow::bail!(
+                "Missing required fields for `CryptoPerpetual`: {}",
+                self.missing_fields().join(", ")
+            );
+        };
+
+        Ok(constructor(
+            id,
+            self.raw_symbol.unwrap_or(id.symbol),
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            self.is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            self.multiplier,
+            self.lot_size,
+            self.max_quantity,
+            self.min_quantity,
+            self.max_notional,
+            self.min_notional,
+            self.max_price,
+            self.min_price,
+            self.margin_init,
+            self.margin_maint,
+            self.maker_fee,
+            self.taker_fee,
+            self.funding_rate,
+            self.funding_interval_ns,
+            self.ts_event,
+            self.ts_init,
+        )?)
+    }
+
+    /// Returns the names of the required fields which have not been set.
+    fn missing_fields(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if self.id.is_none() {
+            missing.push(stringify!(id));
+        }
+        if self.base_currency.is_none() {
+            missing.push(stringify!(base_currency));
+        }
+        if self.quote_currency.is_none() {
+            missing.push(stringify!(quote_currency));
+        }
+        if self.settlement_currency.is_none() {
+            missing.push(stringify!(settlement_currency));
+        }
+        if self.price_precision.is_none() {
+            missing.push(stringify!(price_precision));
+        }
+        if self.size_precision.is_none() {
+            missing.push(stringify!(size_precision));
+        }
+        if self.price_increment.is_none() {
+            missing.push(stringify!(price_increment));
+        }
+        if self.size_increment.is_none() {
+            missing.push(stringify!(size_increment));
+        }
+        missing
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+    use proptest::prelude::*;
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        enums::{InstrumentClass, LiquiditySide, OrderSide, PositionSide},
+        identifiers::{InstrumentId, Symbol},
+        instruments::{
+            crypto_perpetual::{CryptoPerpetualBuilder, FieldDiff, PerpetualSpec},
+            stubs::*,
+            CryptoFuture, CryptoPerpetual, Instrument, InstrumentAny, InstrumentError,
+            RoundingMode, PRICE_LADDER_MAX_LEVELS,
+        },
+        types::{BasisPoints, Currency, CurrencyResolver, Money, Price, Quantity, QUANTITY_MAX},
+    };
+
+    #[rstest]
+    fn test_equality(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let cloned = crypto_perpetual_ethusdt;
+        assert_eq!(crypto_perpetual_ethusdt, cloned);
+    }
+
+    #[rstest]
+    fn test_calculate_notional_value_linear(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let quantity = Quantity::from("10000");
+        let price = Price::from("50000.00");
+        let notional = crypto_perpetual_ethusdt.calculate_notional_value(quantity, price, None);
+        assert_eq!(notional, Money::from("500000000 USDT"));
+        assert_eq!(notional.currency, Currency::USDT());
+    }
+
+    #[rstest]
+    fn test_calculate_notional_value_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        let quantity = Quantity::from("10000");
+        let price = Price::from("50000.0");
+        let notional = xbtusd_bitmex.calculate_notional_value(quantity, price, None);
+        assert_eq!(notional, Money::from("0.2 BTC"));
+        assert_eq!(notional.currency, Currency::BTC());
+        assert_eq!(notional.currency.precision, 8);
+    }
+
+    #[rstest]
+    fn test_calculate_notional_value_inverse_use_quote(xbtusd_bitmex: CryptoPerpetual) {
+        let quantity = Quantity::from("10000");
+        let price = Price::from("50000.0");
+        let notional = xbtusd_bitmex.calculate_notional_value(quantity, price, Some(true));
+        assert_eq!(notional, Money::from("10000 USD"));
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_linear(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let quantity = Quantity::from("1000");
+        let last_px = Price::from("2500.00");
+        let result = crypto_perpetual_ethusdt.calculate_base_quantity(quantity, last_px);
+        assert_eq!(result, Quantity::from("0.400"));
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_linear_with_rounding(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let quantity = Quantity::from("100");
+        let last_px = Price::from("3000.00");
+        let result = crypto_perpetual_ethusdt.calculate_base_quantity(quantity, last_px);
+        assert_eq!(result, Quantity::from("0.033"));
+        assert_eq!(result.precision, 3);
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        let quantity = Quantity::from("125000");
+        let last_px = Price::from("50000.0");
+        let result = xbtusd_bitmex.calculate_base_quantity(quantity, last_px);
+        assert_eq!(result, Quantity::from("2")); // Rounded to size increment of 1
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_saturates_at_quantity_max(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let quantity = Quantity::from(1_000_000_000);
+        let last_px = Price::from("0.01");
+        let result = crypto_perpetual_ethusdt.calculate_base_quantity(quantity, last_px);
+        assert_eq!(result.as_decimal(), dec!(18446744073.000));
+        assert_eq!(result.precision, 3);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 1, "1999.99")]
+    #[case(OrderSide::Buy, 5, "1999.95")]
+    #[case(OrderSide::Sell, 1, "2000.01")]
+    #[case(OrderSide::Sell, 5, "2000.05")]
+    #[case(OrderSide::Buy, 0, "2000.00")]
+    fn test_improve_price(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: OrderSide,
+        #[case] ticks: u32,
+        #[case] expected: &str,
+    ) {
+        let price = crypto_perpetual_ethusdt.improve_price(Price::from("2000.00"), side, ticks);
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "1.02", 5, "1.00")] // Clamped to `min_price`
+    #[case(OrderSide::Sell, "14999.98", 5, "15000.00")] // Clamped to `max_price`
+    fn test_improve_price_clamped_to_bound(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: OrderSide,
+        #[case] reference: &str,
+        #[case] ticks: u32,
+        #[case] expected: &str,
+    ) {
+        let price = crypto_perpetual_ethusdt.improve_price(Price::from(reference), side, ticks);
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 1, "2000.01")]
+    #[case(OrderSide::Buy, 3, "2000.03")]
+    #[case(OrderSide::Sell, 1, "1999.99")]
+    #[case(OrderSide::Sell, 3, "1999.97")]
+    fn test_apply_slippage(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: OrderSide,
+        #[case] ticks: u32,
+        #[case] expected: &str,
+    ) {
+        let price = crypto_perpetual_ethusdt.apply_slippage(Price::from("2000.00"), side, ticks);
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "14999.98", 5, "15000.00")] // Clamped to `max_price`
+    #[case(OrderSide::Sell, "1.02", 5, "1.00")] // Clamped to `min_price`
+    fn test_apply_slippage_clamped_to_bound(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] ticks: u32,
+        #[case] expected: &str,
+    ) {
+        let price = crypto_perpetual_ethusdt.apply_slippage(Price::from(price), side, ticks);
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    #[case("2000.00", dec!(0.01), "2020.00")]
+    #[case("2000.00", dec!(-0.01), "1980.00")]
+    #[case("2000.37", dec!(0.01), "2020.37")] // Snapped from 2020.3737
+    #[case("2000.00", dec!(0), "2000.00")]
+    fn test_price_at_pct(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] base: &str,
+        #[case] pct: Decimal,
+        #[case] expected: &str,
+    ) {
+        let price = crypto_perpetual_ethusdt.price_at_pct(Price::from(base), pct);
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.precision, 2);
+    }
+
+    #[rstest]
+    #[case(dec!(0.01), "10100.5")] // Snapped from 10100.505
+    #[case(dec!(-0.01), "9900.5")] // Snapped from 9900.495
+    fn test_price_at_pct_coarse_increment(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] pct: Decimal,
+        #[case] expected: &str,
+    ) {
+        let price = xbtusd_bitmex.price_at_pct(Price::from("10000.5"), pct);
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    #[case("2000.00", "2000.05", 1, vec!["2000.00", "2000.01", "2000.02", "2000.03", "2000.04", "2000.05"])]
+    #[case("2000.00", "2000.05", 2, vec!["2000.00", "2000.02", "2000.04"])]
+    #[case("2000.05", "2000.00", 2, vec!["2000.05", "2000.03", "2000.01"])] // Descending
+    #[case("2000.00", "2000.00", 1, vec!["2000.00"])]
+    #[case("2000.00", "2000.05", 0, vec![])] // Zero step
+    fn test_price_ladder(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] from: &str,
+        #[case] to: &str,
+        #[case] step_ticks: u32,
+        #[case] expected: Vec<&str>,
+    ) {
+        let ladder = crypto_perpetual_ethusdt
+            .price_ladder(Price::from(from), Price::from(to), step_ticks)
+            .unwrap();
+        let expected: Vec<Price> = expected.into_iter().map(Price::from).collect();
+        assert_eq!(ladder, expected);
+    }
+
+    #[rstest]
+    fn test_price_ladder_unaligned_bounds(xbtusd_bitmex: CryptoPerpetual) {
+        let ascending = xbtusd_bitmex
+            .price_ladder(Price::from("100.3"), Price::from("101.7"), 1)
+            .unwrap();
+        let descending = xbtusd_bitmex
+            .price_ladder(Price::from("101.7"), Price::from("100.3"), 1)
+            .unwrap();
+        let expected: Vec<Price> = ["100.5", "101.0", "101.5"].map(Price::from).to_vec();
+
+        assert_eq!(ascending, expected);
+        assert_eq!(descending, expected.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    fn test_price_ladder_max_levels(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        // 1000.00 to 1999.99 is exactly the maximum number of one tick levels
+        let ladder = crypto_perpetual_ethusdt
+            .price_ladder(Price::from("1000.00"), Price::from("1999.99"), 1)
+            .unwrap();
+        assert_eq!(ladder.len(), PRICE_LADDER_MAX_LEVELS);
+
+        let result = crypto_perpetual_ethusdt.price_ladder(
+            Price::from("1000.00"),
+            Price::from("2000.00"),
+            1,
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid price ladder: 100001 levels exceeded the maximum 100000"
+        );
+
+        // Wider bounds with a larger step are still within the maximum
+        let ladder = crypto_perpetual_ethusdt
+            .price_ladder(Price::from("0.00"), Price::from("10000.00"), 100)
+            .unwrap();
+        assert_eq!(ladder.len(), 10_001);
+    }
+
+    #[rstest]
+    fn test_make_prices(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let values: Vec<f64> = (0..1_000).map(|i| 2000.0 + f64::from(i) * 0.0037).collect();
+        let mut out = vec![Price::from("1.00")];
+
+        crypto_perpetual_ethusdt.make_prices(&values, &mut out);
+
+        assert_eq!(out.len(), values.len() + 1);
+        assert_eq!(out[0], Price::from("1.00"));
+        for (value, price) in values.iter().zip(&out[1..]) {
+            assert_eq!(*price, crypto_perpetual_ethusdt.make_price(*value));
+            assert_eq!(price.precision, 2);
+        }
+    }
+
+    #[rstest]
+    #[case(100.123, "100.10")]
+    #[case(100.125, "100.10")] // Tie rounds to even (2002 ticks)
+    #[case(100.175, "100.20")] // Tie rounds to even (2004 ticks)
+    #[case(100.1, "100.10")]
+    #[case(0.01, "0.00")]
+    fn test_make_price_rounds_to_increment(
+        ethusdt_bitmex: CryptoPerpetual,
+        #[case] value: f64,
+        #[case] expected: &str,
+    ) {
+        let price = ethusdt_bitmex.make_price(value);
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.precision, 2);
+    }
+
+    #[rstest]
+    #[case(100.123, "100.10", "100.15")]
+    #[case(100.149, "100.10", "100.15")]
+    #[case(100.15, "100.15", "100.15")]
+    fn test_next_bid_and_ask_price(
+        ethusdt_bitmex: CryptoPerpetual,
+        #[case] value: f64,
+        #[case] expected_bid: &str,
+        #[case] expected_ask: &str,
+    ) {
+        assert_eq!(
+            ethusdt_bitmex.next_bid_price(value),
+            Price::from(expected_bid)
+        );
+        assert_eq!(
+            ethusdt_bitmex.next_ask_price(value),
+            Price::from(expected_ask)
+        );
+    }
+
+    #[rstest]
+    #[case(1.2344, "1.234")]
+    #[case(1.2346, "1.235")]
+    #[case(2.7, "2.700")]
+    #[case(0.0004, "0.000")]
+    fn test_make_qty_rounds_to_increment(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] value: f64,
+        #[case] expected: &str,
+    ) {
+        let qty = crypto_perpetual_ethusdt.make_qty(value);
+        assert_eq!(qty, Quantity::from(expected));
+        assert_eq!(qty.precision, 3);
+    }
+
+    #[rstest]
+    #[case(1.2346, "1.000")]
+    #[case(2.7, "2.000")]
+    #[case(0.0004, "0.001")] // Raised to `min_quantity`
+    fn test_make_qty_clamped(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] value: f64,
+        #[case] expected: &str,
+    ) {
+        let qty = crypto_perpetual_ethusdt.make_qty_clamped(value);
+        assert_eq!(qty, Quantity::from(expected));
+    }
+
+    #[rstest]
+    fn test_increment_raw(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        assert_eq!(
+            crypto_perpetual_ethusdt.price_increment_raw(),
+            Price::from("0.01").raw
+        );
+        assert_eq!(
+            crypto_perpetual_ethusdt.size_increment_raw(),
+            Quantity::from("0.001").raw
+        );
+        assert_eq!(crypto_perpetual_ethusdt.price_increment_raw(), 10_000_000);
+        assert_eq!(crypto_perpetual_ethusdt.size_increment_raw(), 1_000_000);
+        assert_eq!(xbtusd_bitmex.price_increment_raw(), Price::from("0.5").raw);
+        assert_eq!(xbtusd_bitmex.size_increment_raw(), Quantity::from("1").raw);
+    }
+
+    #[rstest]
+    #[case("50000.0", 100_000)]
+    #[case("50000.5", 100_001)]
+    #[case("50000.2", 100_000)] // Rounds down to nearest tick
+    #[case("50000.3", 100_001)] // Rounds up to nearest tick
+    #[case("50000.25", 100_000)] // Ties round to even
+    #[case("50000.75", 100_002)] // Ties round to even
+    #[case("-0.5", -1)]
+    fn test_price_to_ticks(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] price: &str,
+        #[case] expected: i64,
+    ) {
+        assert_eq!(xbtusd_bitmex.price_to_ticks(Price::from(price)), expected);
+    }
+
+    #[rstest]
+    fn test_ticks_between(xbtusd_bitmex: CryptoPerpetual) {
+        let bid = Price::from("50000.0");
+        let ask = Price::from("50002.0");
+
+        assert_eq!(xbtusd_bitmex.ticks_between(bid, ask), 4);
+        assert_eq!(xbtusd_bitmex.ticks_between(ask, bid), -4);
+        assert_eq!(xbtusd_bitmex.ticks_between(bid, bid), 0);
+    }
+
+    #[rstest]
+    #[case(Some("100.0"), Some("200.0"), Some(200))]
+    #[case(Some("100.0"), Some("200.2"), Some(200))] // Partial tick rounded down
+    #[case(Some("100.0"), Some("100.0"), Some(0))]
+    #[case(None, Some("200.0"), None)]
+    #[case(Some("100.0"), None, None)]
+    fn test_tick_count(
+        #[case] min_price: Option<&str>,
+        #[case] max_price: Option<&str>,
+        #[case] expected: Option<u64>,
+    ) {
+        let mut builder = builder_with_limits();
+        builder.price_increment(Price::from("0.5"));
+        if let Some(min_price) = min_price {
+            builder.min_price(Price::from(min_price));
+        }
+        if let Some(max_price) = max_price {
+            builder.max_price(Price::from(max_price));
+        }
+        let instrument = builder.build().unwrap();
+
+        assert_eq!(instrument.tick_count(), expected);
+    }
+
+    #[rstest]
+    fn test_venue_str(crypto_perpetual_ethusdt: CryptoPerpetual, xbtusd_bitmex: CryptoPerpetual) {
+        assert_eq!(crypto_perpetual_ethusdt.exchange(), None);
+        assert_eq!(crypto_perpetual_ethusdt.venue_str(), "BINANCE");
+        assert_eq!(xbtusd_bitmex.venue_str(), "BITMEX");
+    }
+
+    #[rstest]
+    fn test_sort_by_id(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+        ethusdt_bitmex: CryptoPerpetual,
+    ) {
+        let mut instruments = vec![crypto_perpetual_ethusdt, xbtusd_bitmex, ethusdt_bitmex];
+        instruments.sort();
+
+        let ids: Vec<String> = instruments.iter().map(|i| i.id.to_string()).collect();
+        let mut expected = ids.clone();
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert_eq!(
+            ids,
+            vec!["BTCUSDT.BITMEX", "ETHUSD.BITMEX", "ETHUSDT-PERP.BINANCE"]
+        );
+    }
+
+    #[rstest]
+    fn test_ord_uses_string_representation() {
+        let perpetual = |id: &str| {
+            let mut instrument = crypto_perpetual_ethusdt();
+            instrument.id = InstrumentId::from(id);
+            instrument
+        };
+        // Symbol ordering alone would place "A" before "A-B"
+        assert!(perpetual("A-B.SIM") < perpetual("A.SIM"));
+        assert_eq!(
+            perpetual("A.SIM").cmp(&perpetual("A.SIM")),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[rstest]
+    fn test_validate_all_valid(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        let catalog = [crypto_perpetual_ethusdt, xbtusd_bitmex];
+        assert!(CryptoPerpetual::validate_all(&catalog).is_ok());
+    }
+
+    #[rstest]
+    fn test_diff(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut other = crypto_perpetual_ethusdt;
+        other.taker_fee = dec!(0.0005);
+        other.max_quantity = None;
+        other.ts_event = UnixNanos::from(1);
+        other.ts_init = UnixNanos::from(2);
+
+        let diffs = crypto_perpetual_ethusdt.diff(&other);
+
+        assert_eq!(
+            diffs,
+            vec![
+                FieldDiff {
+                    name: "taker_fee",
+                    old: "0.0004".to_string(),
+                    new: "0.0005".to_string(),
+                },
+                FieldDiff {
+                    name: "max_quantity",
+                    old: "10000.0".to_string(),
+                    new: "None".to_string(),
+                },
+            ]
+        );
+        assert_eq!(diffs[0].to_string(), "taker_fee: 0.0004 -> 0.0005");
+    }
+
+    #[rstest]
+    fn test_diff_identical(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        assert!(crypto_perpetual_ethusdt
+            .diff(&crypto_perpetual_ethusdt)
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_is_increment_aligned(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        assert!(crypto_perpetual_ethusdt.is_increment_aligned());
+        assert!(xbtusd_bitmex.is_increment_aligned());
+    }
+
+    #[rstest]
+    fn test_is_increment_aligned_misaligned(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.price_precision = 3;
+        instrument.price_increment = Price::from("0.100");
+        assert!(!instrument.is_increment_aligned());
+
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.size_precision = 2;
+        assert!(!instrument.is_increment_aligned());
+    }
+
+    #[rstest]
+    fn test_normalize(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.price_precision = 3;
+        instrument.price_increment = Price::from("0.100");
+        instrument.size_precision = 5;
+        instrument.size_increment = Quantity::from("0.00100");
+
+        instrument.normalize().unwrap();
+
+        assert!(instrument.is_increment_aligned());
+        assert_eq!(instrument.price_precision, 1);
+        assert_eq!(instrument.price_increment, Price::from("0.1"));
+        assert_eq!(instrument.price_increment.precision, 1);
+        assert_eq!(instrument.size_precision, 3);
+        assert_eq!(instrument.size_increment.precision, 3);
+        assert_eq!(instrument.max_price, Some(Price::from("15000.0")));
+        assert_eq!(instrument.min_price, Some(Price::from("1.0")));
+        assert_eq!(instrument.max_quantity, Some(Quantity::from("10000.000")));
+        assert_eq!(instrument.min_quantity.unwrap().precision, 3);
+    }
+
+    #[rstest]
+    fn test_normalize_aligned_is_unchanged(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.normalize().unwrap();
+        assert!(instrument.equals_full(&crypto_perpetual_ethusdt));
+    }
+
+    #[rstest]
+    fn test_normalize_unrepresentable_limit(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.price_precision = 3;
+        instrument.price_increment = Price::from("0.100");
+        instrument.min_price = Some(Price::from("1.005"));
+        let before = instrument;
+
+        let result = instrument.normalize();
+
+        assert_eq!(
+            result,
+            Err(InstrumentError::InvalidLimit {
+                instrument_id: instrument.id,
+                field: "min_price",
+                value: "1.005".to_string(),
+            })
+        );
+        assert!(instrument.equals_full(&before));
+    }
+
+    #[rstest]
+    fn test_validate_all_reports_every_invalid_instrument(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+        ethusdt_bitmex: CryptoPerpetual,
+    ) {
+        let mut bad_price = xbtusd_bitmex;
+        bad_price.price_precision = 2;
+        let mut bad_size = ethusdt_bitmex;
+        bad_size.size_precision = 3;
+        let catalog = [bad_price, crypto_perpetual_ethusdt, bad_size];
+
+        let errors = CryptoPerpetual::validate_all(&catalog).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                InstrumentError::PrecisionMismatch {
+                    instrument_id: bad_price.id,
+                    field: "price_precision",
+                    precision: 2,
+                    increment_field: "price_increment",
+                    increment_precision: 1,
+                },
+                InstrumentError::PrecisionMismatch {
+                    instrument_id: bad_size.id,
+                    field: "size_precision",
+                    precision: 3,
+                    increment_field: "size_increment",
+                    increment_precision: 0,
+                },
+            ]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "Invalid instrument BTCUSDT.BITMEX: `price_precision` 2 did not match `price_increment.precision` 1"
+        );
+    }
+
+    #[rstest]
+    fn test_try_from_instrument_any(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let any = crypto_perpetual_ethusdt.into_any();
+
+        assert_eq!(any.as_crypto_perpetual(), Some(&crypto_perpetual_ethusdt));
+        let instrument = CryptoPerpetual::try_from(any).unwrap();
+        assert!(instrument.equals_full(&crypto_perpetual_ethusdt));
+    }
+
+    #[rstest]
+    fn test_try_from_instrument_any_wrong_variant(crypto_future_btcusdt: CryptoFuture) {
+        let any = InstrumentAny::CryptoFuture(crypto_future_btcusdt);
+
+        assert_eq!(any.as_crypto_perpetual(), None);
+        let result = CryptoPerpetual::try_from(any);
+        assert_eq!(
+            result.unwrap_err(),
+            InstrumentError::InvalidVariant(crypto_future_btcusdt.id, "CryptoPerpetual")
+        );
+    }
+
+    #[rstest]
+    #[case("100.00", "100.02", "100.01")] // Exactly on a tick
+    #[case("100.00", "100.01", "100.00")] // Half tick rounds to even
+    #[case("100.01", "100.02", "100.02")] // Half tick rounds to even
+    #[case("100.01", "100.01", "100.01")]
+    fn test_mid_price(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] bid: &str,
+        #[case] ask: &str,
+        #[case] expected: &str,
+    ) {
+        let mid = crypto_perpetual_ethusdt.mid_price(Price::from(bid), Price::from(ask));
+        assert_eq!(mid, Price::from(expected));
+        assert_eq!(mid.precision, 2);
+    }
+
+    #[rstest]
+    #[case("50000.0", "50001.0", "50000.5", "50000.5")] // Exactly on a tick
+    #[case("50000.0", "50000.5", "50000.2", "50000.0")] // Ties round to even
+    #[case("50000.5", "50001.0", "50000.8", "50001.0")] // Ties round to even
+    #[case("50000.0", "50002.0", "50001.0", "50001.0")]
+    fn test_mid_price_on_tick(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] bid: &str,
+        #[case] ask: &str,
+        #[case] expected_mid: &str,
+        #[case] expected_on_tick: &str,
+    ) {
+        let (bid, ask) = (Price::from(bid), Price::from(ask));
+        assert_eq!(xbtusd_bitmex.mid_price(bid, ask), Price::from(expected_mid));
+        assert_eq!(
+            xbtusd_bitmex.mid_price_on_tick(bid, ask),
+            Price::from(expected_on_tick)
+        );
+    }
+
+    #[rstest]
+    fn test_rescale_limits(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let rescaled = crypto_perpetual_ethusdt.rescale_limits(dec!(10)).unwrap();
+
+        assert_eq!(rescaled.max_quantity, Some(Quantity::from("100000.000")));
+        assert_eq!(rescaled.min_quantity, Some(Quantity::from("0.010")));
+        assert_eq!(
+            rescaled.lot_size.as_decimal(),
+            crypto_perpetual_ethusdt.lot_size.as_decimal() * dec!(10)
+        );
+        assert_eq!(rescaled.max_notional, None);
+        assert_eq!(rescaled.min_notional, Some(Money::from("100 USDT")));
+
+        assert_eq!(
+            rescaled.price_increment,
+            crypto_perpetual_ethusdt.price_increment
+        );
+        assert_eq!(
+            rescaled.size_increment,
+            crypto_perpetual_ethusdt.size_increment
+        );
+        assert_eq!(rescaled.max_price, crypto_perpetual_ethusdt.max_price);
+        assert_eq!(rescaled.min_price, crypto_perpetual_ethusdt.min_price);
+    }
+
+    fn builder_with_notional_limits() -> CryptoPerpetualBuilder {
+        let mut builder = builder_with_limits();
+        builder
+            .size_precision(3)
+            .size_increment(Quantity::from("0.001"))
+            .lot_size(Quantity::from("1.000"))
+            .max_notional(Money::from("1000000.00 USD"))
+            .min_notional(Money::from("10.50 USD"));
+        builder
+    }
+
+    #[rstest]
+    fn test_rescale_limits_with_max_notional() {
+        let instrument = builder_with_notional_limits()
+            .max_quantity(Quantity::from("1000.000"))
+            .min_quantity(Quantity::from("10.000"))
+            .build()
+            .unwrap();
+
+        let rescaled = instrument.rescale_limits(dec!(0.1)).unwrap();
+
+        assert_eq!(rescaled.max_quantity, Some(Quantity::from("100.000")));
+        assert_eq!(rescaled.min_quantity, Some(Quantity::from("1.000")));
+        assert_eq!(rescaled.lot_size, Quantity::from("0.100"));
+        assert_eq!(rescaled.max_notional, Some(Money::from("100000.00 USD")));
+        assert_eq!(rescaled.min_notional, Some(Money::from("1.05 USD")));
+    }
+
+    #[rstest]
+    #[case(dec!(0.01), "Invalid `min_notional` 0.1050, not representable at precision 2")]
+    #[case(dec!(100000), "Invalid `max_notional` 100000000000.00: ")]
+    fn test_rescale_limits_invalid_notional(#[case] factor: Decimal, #[case] expected: &str) {
+        let instrument = builder_with_notional_limits().build().unwrap();
+
+        let result = instrument.rescale_limits(factor);
+
+        assert!(result.unwrap_err().to_string().starts_with(expected));
+    }
+
+    #[rstest]
+    #[case(dec!(0))]
+    #[case(dec!(-10))]
+    #[case(dec!(0.1))] // min_quantity 0.0001 is not representable at precision 3
+    #[case(dec!(10000000))] // max_quantity exceeds the maximum quantity
+    fn test_rescale_limits_invalid(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] factor: Decimal,
+    ) {
+        assert!(crypto_perpetual_ethusdt.rescale_limits(factor).is_err());
+    }
+
+    #[rstest]
+    fn test_spec_hash(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let map_hash = |instrument: &CryptoPerpetual| {
+            let mut hasher = DefaultHasher::new();
+            instrument.hash(&mut hasher);
+            hasher.finish()
+        };
+        let mut updated = crypto_perpetual_ethusdt;
+        updated.taker_fee = dec!(0.0005);
+
+        assert_ne!(updated.spec_hash(), crypto_perpetual_ethusdt.spec_hash());
+        assert_eq!(map_hash(&updated), map_hash(&crypto_perpetual_ethusdt));
+    }
+
+    #[rstest]
+    fn test_spec_hash_ignores_timestamps_and_decimal_scale(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut updated = crypto_perpetual_ethusdt;
+        updated.ts_event = UnixNanos::from(1);
+        updated.ts_init = UnixNanos::from(2);
+        updated.taker_fee = dec!(0.00040);
+
+        assert_eq!(updated.spec_hash(), crypto_perpetual_ethusdt.spec_hash());
+    }
+
+    #[rstest]
+    fn test_spec_hash_is_stable(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        // Persisted hashes must remain comparable, so the value must not change
+        assert_eq!(
+            crypto_perpetual_ethusdt.spec_hash(),
+            7_873_679_435_344_051_077
+        );
+    }
+
+    #[rstest]
+    fn test_equals_full(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut updated = crypto_perpetual_ethusdt;
+        updated.taker_fee = dec!(0.0005);
+
+        assert_eq!(crypto_perpetual_ethusdt, updated);
+        assert!(crypto_perpetual_ethusdt.equals_full(&crypto_perpetual_ethusdt));
+        assert!(!crypto_perpetual_ethusdt.equals_full(&updated));
+    }
+
+    #[rstest]
+    #[case(LiquiditySide::Maker, "0.40 USDT")]
+    #[case(LiquiditySide::Taker, "0.80 USDT")]
+    fn test_calculate_fee_linear(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] liquidity_side: LiquiditySide,
+        #[case] expected: &str,
+    ) {
+        let fee = crypto_perpetual_ethusdt.calculate_fee(
+            Quantity::from("1.000"),
+            Price::from("2000.00"),
+            liquidity_side,
+        );
+        assert_eq!(fee, Money::from(expected));
+    }
+
+    #[rstest]
+    #[case(LiquiditySide::Maker, "-0.00000050 BTC")]
+    #[case(LiquiditySide::Taker, "0.00000150 BTC")]
+    fn test_calculate_fee_inverse(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] liquidity_side: LiquiditySide,
+        #[case] expected: &str,
+    ) {
+        let fee = xbtusd_bitmex.calculate_fee(
+            Quantity::from(100),
+            Price::from("50000.0"),
+            liquidity_side,
+        );
+        assert_eq!(fee, Money::from(expected));
+        assert_eq!(fee.currency, xbtusd_bitmex.settlement_currency);
+    }
+
+    #[rstest]
+    #[case(1, LiquiditySide::Taker, "0.00000002 BTC")] // 0.000000015
+    #[case(3, LiquiditySide::Taker, "0.00000004 BTC")] // 0.000000045
+    #[case(1, LiquiditySide::Maker, "0.00000000 BTC")] // -0.000000005
+    fn test_calculate_fee_inverse_rounds_half_even(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] quantity: i64,
+        #[case] liquidity_side: LiquiditySide,
+        #[case] expected: &str,
+    ) {
+        let fee = xbtusd_bitmex.calculate_fee(
+            Quantity::from(quantity),
+            Price::from("50000.0"),
+            liquidity_side,
+        );
+        assert_eq!(fee, Money::from(expected));
+        assert_eq!(fee.currency.precision, 8);
+    }
+
+    #[rstest]
+    fn test_calculate_notional_and_margin_on_midpoint_round_once() {
+        let instrument = builder_with_limits()
+            .price_precision(3)
+            .price_increment(Price::from("0.001"))
+            .margin_init(dec!(0.3))
+            .build()
+            .unwrap();
+        let quantity = Quantity::from(1);
+        let price = Price::from("100.015"); // Notional sits on a midpoint at USD precision
+
+        let notional = instrument.calculate_notional_value(quantity, price, None);
+        let margin_init = instrument.calculate_margin_init(quantity, price);
+
+        assert_eq!(notional, Money::from("100.02 USD"));
+        // 30.0045 rounds to 30.00, rounding the notional first would give 30.01
+        assert_eq!(margin_init, Money::from("30.00 USD"));
+    }
+
+    #[rstest]
+    fn test_calculate_margin_linear() {
+        let instrument = CryptoPerpetualBuilder::new()
+            .id(InstrumentId::from("BTCUSD-PERP.SIM"))
+            .base_currency(Currency::BTC())
+            .quote_currency(Currency::USD())
+            .settlement_currency(Currency::USD())
+            .price_precision(2)
+            .size_precision(3)
+            .price_increment(Price::from("0.01"))
+            .size_increment(Quantity::from("0.001"))
+            .margin_init(dec!(0.10))
+            .margin_maint(dec!(0.05))
+            .build()
+            .unwrap();
+        let quantity = Quantity::from("0.200");
+        let price = Price::from("50000.00"); // $10,000 notional
+
+        let margin_init = instrument.calculate_margin_init(quantity, price);
+        let margin_maint = instrument.calculate_margin_maint(quantity, price);
+
+        assert_eq!(margin_init, Money::from("1000 USD"));
+        assert_eq!(margin_maint, Money::from("500 USD"));
+    }
+
+    #[rstest]
+    fn test_calculate_margin_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        let quantity = Quantity::from(10_000);
+        let price = Price::from("50000.0"); // 0.2 BTC notional
+
+        let margin_init = xbtusd_bitmex.calculate_margin_init(quantity, price);
+        let margin_maint = xbtusd_bitmex.calculate_margin_maint(quantity, price);
+
+        assert_eq!(margin_init, Money::from("0.00200000 BTC"));
+        assert_eq!(margin_maint, Money::from("0.00070000 BTC"));
+        assert_eq!(margin_init.currency, xbtusd_bitmex.base_currency);
+    }
+
+    #[rstest]
+    fn test_calculate_margin_third_settlement_currency() {
+        // USDC settled, USDT quoted: the margin is in the notional (quote) currency
+        let instrument = CryptoPerpetualBuilder::new()
+            .id(InstrumentId::from("ETHUSDT-PERP.SIM"))
+            .base_currency(Currency::ETH())
+            .quote_currency(Currency::USDT())
+            .settlement_currency(Currency::USDC())
+            .price_precision(2)
+            .size_precision(3)
+            .price_increment(Price::from("0.01"))
+            .size_increment(Quantity::from("0.001"))
+            .margin_init(dec!(0.10))
+            .margin_maint(dec!(0.05))
+            .build()
+            .unwrap();
+        let quantity = Quantity::from("1.000");
+        let price = Price::from("2000.00");
+
+        let margin_init = instrument.calculate_margin_init(quantity, price);
+        let margin_maint = instrument.calculate_margin_maint(quantity, price);
+
+        assert_eq!(margin_init, Money::from("200 USDT"));
+        assert_eq!(margin_maint, Money::from("100 USDT"));
+        assert_eq!(
+            instrument.max_qty_for_margin(margin_init, price).unwrap(),
+            quantity
+        );
+        assert!(instrument
+            .max_qty_for_margin(Money::from("200 USDC"), price)
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_is_expired_without_expiration(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        assert_eq!(crypto_perpetual_ethusdt.expiration_ns(), None);
+        assert!(!crypto_perpetual_ethusdt.is_expired(UnixNanos::default()));
+        assert!(!crypto_perpetual_ethusdt.is_expired(UnixNanos::MAX));
+    }
+
+    #[rstest]
+    fn test_is_tradable_without_activation_or_expiration(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        assert!(crypto_perpetual_ethusdt.is_tradable(UnixNanos::default()));
+        assert!(crypto_perpetual_ethusdt.is_tradable(UnixNanos::MAX));
+    }
+
+    #[rstest]
+    fn test_is_cash_settled(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        for instrument in [crypto_perpetual_ethusdt, xbtusd_bitmex] {
+            assert_eq!(instrument.instrument_class(), InstrumentClass::Swap);
+            assert!(instrument.is_cash_settled());
+            assert!(!instrument.is_physically_settled());
+        }
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, "2000.00", "2100.00", "1.500", "150.00000000 USDT")]
+    #[case(
+        PositionSide::Long,
+        "2100.00",
+        "2000.00",
+        "1.500",
+        "-150.00000000 USDT"
+    )]
+    #[case(
+        PositionSide::Short,
+        "2000.00",
+        "2100.00",
+        "1.500",
+        "-150.00000000 USDT"
+    )]
+    #[case(
+        PositionSide::Short,
+        "2100.00",
+        "2000.00",
+        "1.500",
+        "150.00000000 USDT"
+    )]
+    #[case(PositionSide::Flat, "2000.00", "2100.00", "1.500", "0 USDT")]
+    fn test_calculate_pnl_linear(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: PositionSide,
+        #[case] entry: &str,
+        #[case] exit: &str,
+        #[case] quantity: &str,
+        #[case] expected: &str,
+    ) {
+        let pnl = crypto_perpetual_ethusdt.calculate_pnl(
+            Price::from(entry),
+            Price::from(exit),
+            Quantity::from(quantity),
+            side,
+        );
+        assert_eq!(pnl, Money::from(expected));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, "40000.0", "50000.0", "0.05000000 BTC")]
+    #[case(PositionSide::Long, "50000.0", "40000.0", "-0.05000000 BTC")]
+    #[case(PositionSide::Short, "40000.0", "50000.0", "-0.05000000 BTC")]
+    #[case(PositionSide::Short, "50000.0", "40000.0", "0.05000000 BTC")]
+    fn test_calculate_pnl_inverse(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] side: PositionSide,
+        #[case] entry: &str,
+        #[case] exit: &str,
+        #[case] expected: &str,
+    ) {
+        // 10,000 contracts * (1 / 40,000 - 1 / 50,000) = 0.05 BTC
+        let pnl = xbtusd_bitmex.calculate_pnl(
+            Price::from(entry),
+            Price::from(exit),
+            Quantity::from(10_000),
+            side,
+        );
+        assert_eq!(pnl, Money::from(expected));
+        assert_eq!(pnl.currency, Currency::BTC());
+    }
+
+    #[rstest]
+    #[case("2100.00", "100.00 USDT", "150.00 USDT")]
+    #[case("1900.00", "-100.00 USDT", "-150.00 USDT")]
+    fn test_calculate_pnl_split_linear_long_partial_close(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] current: &str,
+        #[case] expected_realized: &str,
+        #[case] expected_unrealized: &str,
+    ) {
+        let entry = Price::from("2000.00");
+        let current = Price::from(current);
+
+        // Long 2.5 ETH, with 1 ETH closed and 1.5 ETH remaining open
+        let (realized, unrealized) = crypto_perpetual_ethusdt.calculate_pnl_split(
+            entry,
+            current,
+            Quantity::from("1.000"),
+            Quantity::from("1.500"),
+            PositionSide::Long,
+        );
+
+        assert_eq!(realized, Money::from(expected_realized));
+        assert_eq!(unrealized, Money::from(expected_unrealized));
+        assert_eq!(
+            realized + unrealized,
+            crypto_perpetual_ethusdt.calculate_pnl(
+                entry,
+                current,
+                Quantity::from("2.500"),
+                PositionSide::Long
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_calculate_pnl_split_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        let (realized, unrealized) = xbtusd_bitmex.calculate_pnl_split(
+            Price::from("50000.0"),
+            Price::from("40000.0"),
+            Quantity::from(10_000),
+            Quantity::from(30_000),
+            PositionSide::Short,
+        );
+
+        // 10,000 * (1 / 40,000 - 1 / 50,000) = 0.05 BTC
+        assert_eq!(realized, Money::from("0.05 BTC"));
+        assert_eq!(unrealized, Money::from("0.15 BTC"));
+        assert_eq!(realized.currency, unrealized.currency);
+    }
+
+    #[rstest]
+    fn test_zero_notional(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        let zero = crypto_perpetual_ethusdt.zero_notional();
+        assert!(zero.is_zero());
+        assert_eq!(zero.currency, Currency::USDT());
+        assert_eq!(zero.to_string(), "0.00000000 USDT");
+
+        // Inverse instruments are margined in the base currency
+        let zero = xbtusd_bitmex.zero_notional();
+        assert_eq!(zero.currency, Currency::BTC());
+        assert_eq!(zero.to_string(), "0.00000000 BTC");
+        let pnl = xbtusd_bitmex.calculate_pnl(
+            Price::from("50000.0"),
+            Price::from("55000.0"),
+            Quantity::from(10_000),
+            PositionSide::Long,
+        );
+        assert_eq!(zero + pnl, pnl);
+    }
+
+    #[rstest]
+    fn test_zero_notional_third_settlement_currency() {
+        let instrument = builder_btcusdt(Currency::USDC()).build().unwrap();
+
+        let zero = instrument.zero_notional();
+        let pnl = instrument.calculate_pnl(
+            Price::from("50000.0"),
+            Price::from("51000.0"),
+            Quantity::from("0.100"),
+            PositionSide::Long,
+        );
+
+        // PnL is in the quote currency rather than the USDC settlement currency
+        assert_eq!(zero.currency, Currency::USDT());
+        assert_eq!(zero + pnl, Money::from("100.00000000 USDT"));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, "2001.21")] // 2000 * 1.0002 / 0.9996 = 2001.2004...
+    #[case(PositionSide::Short, "1998.80")] // 2000 * 0.9998 / 1.0004 = 1998.8004...
+    #[case(PositionSide::Flat, "2000.00")]
+    fn test_break_even_price_linear(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: PositionSide,
+        #[case] expected: &str,
+    ) {
+        // maker_fee 0.0002 and taker_fee 0.0004
+        let entry = Price::from("2000.00");
+        let quantity = Quantity::from("10.000");
+        let exit = crypto_perpetual_ethusdt.break_even_price(entry, side);
+        assert_eq!(exit, Price::from(expected));
+
+        let pnl = crypto_perpetual_ethusdt.calculate_pnl(entry, exit, quantity, side);
+        let fees = crypto_perpetual_ethusdt.calculate_fee(quantity, entry, LiquiditySide::Maker)
+            + crypto_perpetual_ethusdt.calculate_fee(quantity, exit, LiquiditySide::Taker);
+        if side != PositionSide::Flat {
+            assert!(pnl >= fees);
+        }
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, "10005.0")] // 10000 * 1.00075 / 1.00025 = 10004.998...
+    #[case(PositionSide::Short, "9994.5")] // 10000 * 0.99925 / 0.99975 = 9994.998...
+    fn test_break_even_price_inverse(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] side: PositionSide,
+        #[case] expected: &str,
+    ) {
+        // maker_fee -0.00025 (rebate) and taker_fee 0.00075, with fees in BTC
+        let entry = Price::from("10000.0");
+        let exit = xbtusd_bitmex.break_even_price(entry, side);
+        assert_eq!(exit, Price::from(expected));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, "45226.2")] // 45000 / 0.995 = 45226.130...
+    #[case(PositionSide::Short, "54726.3")] // 55000 / 1.005 = 54726.368...
+    fn test_liquidation_price_linear(#[case] side: PositionSide, #[case] expected: &str) {
+        let instrument = builder_with_limits()
+            .margin_maint(dec!(0.005))
+            .build()
+            .unwrap();
+        let entry = Price::from("50000.0");
+        let quantity = Quantity::from(1);
+        let wallet_balance = Money::from("5000 USD");
+
+        let price = instrument
+            .liquidation_price(entry, quantity, side, wallet_balance)
+            .unwrap();
+        assert_eq!(price, Price::from(expected));
+
+        // Equity at the liquidation price is within one tick of the maintenance margin
+        let equity = wallet_balance + instrument.calculate_pnl(entry, price, quantity, side);
+        let margin_maint = instrument.calculate_margin_maint(quantity, price);
+        assert!((equity - margin_maint).as_decimal().abs() <= dec!(0.1));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, "45614.0")] // 10035 / 0.22 = 45613.636...
+    #[case(PositionSide::Short, "55361.0")] // 9965 / 0.18 = 55361.111...
+    fn test_liquidation_price_inverse(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] side: PositionSide,
+        #[case] expected: &str,
+    ) {
+        // margin_maint 0.0035, with a notional of 0.2 BTC at entry
+        let price = xbtusd_bitmex.liquidation_price(
+            Price::from("50000.0"),
+            Quantity::from(10_000),
+            side,
+            Money::from("0.02 BTC"),
+        );
+        assert_eq!(price, Some(Price::from(expected)));
+    }
+
+    #[rstest]
+    #[case(0, PositionSide::Long, "5000 USD")]
+    #[case(1, PositionSide::Flat, "5000 USD")]
+    #[case(1, PositionSide::Long, "5000 USDT")]
+    #[case(1, PositionSide::Long, "60000 USD")] // Fully collateralized
+    fn test_liquidation_price_degenerate(
+        #[case] quantity: i64,
+        #[case] side: PositionSide,
+        #[case] wallet_balance: &str,
+    ) {
+        let instrument = builder_with_limits()
+            .margin_maint(dec!(0.005))
+            .build()
+            .unwrap();
+        let price = instrument.liquidation_price(
+            Price::from("50000.0"),
+            Quantity::from(quantity),
+            side,
+            Money::from(wallet_balance),
+        );
+        assert_eq!(price, None);
+    }
+
+    #[rstest]
+    #[case("1.2341", RoundingMode::Floor, "1.234")]
+    #[case("1.2341", RoundingMode::Ceil, "1.235")]
+    #[case("1.2341", RoundingMode::Nearest, "1.234")]
+    #[case("1.2346", RoundingMode::Nearest, "1.235")]
+    #[case("1.2345", RoundingMode::Nearest, "1.234")] // Ties round to even
+    #[case("1.2355", RoundingMode::Nearest, "1.236")] // Ties round to even
+    #[case("1.234", RoundingMode::Floor, "1.234")]
+    #[case("1.234", RoundingMode::Ceil, "1.234")]
+    fn test_quantize_qty(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] qty: &str,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let result = crypto_perpetual_ethusdt.quantize_qty(Quantity::from(qty), mode);
+        assert_eq!(result, Quantity::from(expected));
+        assert_eq!(result.precision, 3);
+    }
+
+    #[rstest]
+    #[case("1000.00")]
+    #[case("50000.00")]
+    fn test_tick_value_linear(crypto_perpetual_ethusdt: CryptoPerpetual, #[case] price: &str) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.price_increment = Price::from("0.50");
+
+        let tick_value = instrument.tick_value(Price::from(price));
+
+        assert_eq!(tick_value, Money::from("0.50 USDT"));
+    }
+
+    #[rstest]
+    fn test_tick_value_linear_with_multiplier(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.multiplier = Quantity::from(10);
+
+        let tick_value = instrument.tick_value(Price::from("2000.00"));
+
+        assert_eq!(tick_value, Money::from("0.10 USDT"));
+    }
+
+    #[rstest]
+    fn test_tick_value_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        // 1 contract * 0.5 / 100^2 = 0.00005 BTC
+        let tick_value = xbtusd_bitmex.tick_value(Price::from("100.0"));
+
+        assert_eq!(tick_value, Money::from("0.00005000 BTC"));
+        assert_eq!(tick_value.currency, Currency::BTC());
+    }
+
+    #[rstest]
+    #[case("2000.00 USDT", "2000.00", "1.000")]
+    #[case("1234.56 USDT", "2000.00", "0.617")] // Rounded to size increment
+    #[case("1.00 USDT", "2000.00", "0.001")] // Clamped to `min_quantity`
+    #[case("50000000.00 USDT", "2000.00", "10000.000")] // Clamped to `max_quantity`
+    fn test_notional_to_quantity_linear(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] notional: &str,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        let quantity = crypto_perpetual_ethusdt
+            .notional_to_quantity(Money::from(notional), Price::from(price))
+            .unwrap();
+        assert_eq!(quantity, Quantity::from(expected));
+    }
+
+    #[rstest]
+    #[case("0.1 BTC", "50000.0", "5000")]
+    #[case("0.123456 BTC", "50000.0", "6173")] // Rounded to size increment
+    fn test_notional_to_quantity_inverse(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] notional: &str,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        let quantity = xbtusd_bitmex
+            .notional_to_quantity(Money::from(notional), Price::from(price))
+            .unwrap();
+        assert_eq!(quantity, Quantity::from(expected));
+    }
+
+    #[rstest]
+    fn test_notional_to_quantity_saturates_at_quantity_max() {
+        // No `max_quantity`, and the notional at a low price exceeds `QUANTITY_MAX`
+        let instrument = builder_with_limits()
+            .price_precision(2)
+            .price_increment(Price::from("0.01"))
+            .build()
+            .unwrap();
+        let quantity = instrument
+            .notional_to_quantity(Money::from("1000000000 USD"), Price::from("0.01"))
+            .unwrap();
+        assert_eq!(quantity, Quantity::from(QUANTITY_MAX as i64));
+    }
+
+    #[rstest]
+    fn test_notional_to_quantity_at_precision_9() {
+        let instrument = builder_with_limits()
+            .size_precision(9)
+            .size_increment(Quantity::from("0.000000001"))
+            .build()
+            .unwrap();
+        // 9000000000 / 0.7 = 12857142857.142857142857..., beyond the precision of an `f64`
+        let quantity = instrument
+            .notional_to_quantity(Money::from("9000000000.00 USD"), Price::from("0.7"))
+            .unwrap();
+        assert_eq!(quantity.raw, 12_857_142_857_142_857_143);
+    }
+
+    #[rstest]
+    fn test_notional_to_quantity_currency_mismatch(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        let result = crypto_perpetual_ethusdt
+            .notional_to_quantity(Money::from("1000 USD"), Price::from("2000.00"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid notional currency USD, expected USDT"
+        );
+        let result =
+            xbtusd_bitmex.notional_to_quantity(Money::from("1000 USD"), Price::from("50000.0"));
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case("1000.00 USDT", "2000.00", "0.500")]
+    #[case("1000.00 USDT", "3000.00", "0.333")] // Rounded down to size increment
+    #[case("100000000.00 USDT", "2000.00", "10000.000")] // Capped at max quantity
+    fn test_max_qty_for_margin_linear(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] budget: &str,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        // margin_init 1.0
+        let quantity = crypto_perpetual_ethusdt
+            .max_qty_for_margin(Money::from(budget), Price::from(price))
+            .unwrap();
+        assert_eq!(quantity, Quantity::from(expected));
+        assert!(
+            crypto_perpetual_ethusdt.calculate_margin_init(quantity, Price::from(price))
+                <= Money::from(budget)
+        );
+    }
+
+    #[rstest]
+    #[case("0.01 BTC", "50000.0", "50000")]
+    #[case("0.0123 BTC", "50000.0", "61500")]
+    #[case("0.00000001 BTC", "50000.0", "0")] // Below a single contract
+    fn test_max_qty_for_margin_inverse(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] budget: &str,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        // margin_init 0.01
+        let quantity = xbtusd_bitmex
+            .max_qty_for_margin(Money::from(budget), Price::from(price))
+            .unwrap();
+        assert_eq!(quantity, Quantity::from(expected));
+    }
+
+    #[rstest]
+    fn test_max_qty_for_margin_currency_mismatch(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        let result = crypto_perpetual_ethusdt
+            .max_qty_for_margin(Money::from("1000 USD"), Price::from("2000.00"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid margin budget currency USD, expected USDT"
+        );
+        let result =
+            xbtusd_bitmex.max_qty_for_margin(Money::from("1000 USD"), Price::from("50000.0"));
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_max_qty_for_margin_saturates_at_quantity_max() {
+        // A large budget at a low price with a tiny margin exceeds `QUANTITY_MAX`
+        let instrument = builder_with_limits()
+            .margin_init(dec!(0.000000001))
+            .build()
+            .unwrap();
+        let quantity = instrument
+            .max_qty_for_margin(Money::from("1000000000 USD"), Price::from("0.1"))
+            .unwrap();
+        assert_eq!(quantity, Quantity::from(QUANTITY_MAX as i64));
+    }
+
+    #[rstest]
+    fn test_min_order_quantity_no_limits() {
+        let instrument = builder_with_limits().build().unwrap();
+        assert_eq!(
+            instrument.min_order_quantity(Price::from("50000.0")),
+            Quantity::from(1)
+        );
+    }
+
+    #[rstest]
+    #[case("50000.0", "1")] // Size increment dominates
+    #[case("300.0", "4")] // Rounded up to size increment
+    fn test_min_order_quantity_min_notional_only(#[case] price: &str, #[case] expected: &str) {
+        let instrument = builder_with_limits()
+            .min_notional(Money::from("1000 USD"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            instrument.min_order_quantity(Price::from(price)),
+            Quantity::from(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_min_order_quantity_min_quantity_only() {
+        let instrument = builder_with_limits()
+            .min_quantity(Quantity::from(5))
+            .build()
+            .unwrap();
+        assert_eq!(
+            instrument.min_order_quantity(Price::from("50000.0")),
+            Quantity::from(5)
+        );
+    }
+
+    #[rstest]
+    #[case("2000.00", "0.005")] // Min notional dominates
+    #[case("3000.00", "0.004")] // Rounded up to size increment
+    #[case("15000.00", "0.001")] // Min quantity dominates
+    fn test_min_order_quantity_both_limits(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        let price = Price::from(price);
+        let quantity = crypto_perpetual_ethusdt.min_order_quantity(price);
+        assert_eq!(quantity, Quantity::from(expected));
+        assert!(crypto_perpetual_ethusdt
+            .validate_order(quantity, price)
+            .is_ok());
+    }
+
+    #[rstest]
+    fn test_min_order_quantity_at_precision_9() {
+        // 17 significant digits, beyond the precision of an `f64`
+        let min_quantity = Quantity::from_raw(12_345_678_123_456_789, 9);
+        let instrument = builder_with_limits()
+            .size_precision(9)
+            .size_increment(Quantity::from_raw(1, 9))
+            .min_quantity(min_quantity)
+            .build()
+            .unwrap();
+        assert_eq!(
+            instrument.min_order_quantity(Price::from("100.0")),
+            min_quantity
+        );
+    }
+
+    #[rstest]
+    #[case("10", "3")] // Capped by max notional
+    #[case("3", "3")] // Within limits
+    #[case("2", "2")]
+    fn test_truncate_to_limits_max_notional(#[case] quantity: &str, #[case] expected: &str) {
+        let instrument = builder_with_limits()
+            .max_notional(Money::from("100000 USD"))
+            .build()
+            .unwrap();
+        let price = Price::from("30000.0");
+
+        let quantity = instrument.truncate_to_limits(Quantity::from(quantity), price);
+
+        assert_eq!(quantity, Quantity::from(expected));
+        assert!(instrument.validate_order(quantity, price).is_ok());
+    }
+
+    #[rstest]
+    #[case("12000.000", "10000.000")] // Capped by max quantity
+    #[case("1.234", "1.234")] // Within limits
+    fn test_truncate_to_limits_max_quantity(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] quantity: &str,
+        #[case] expected: &str,
+    ) {
+        let quantity = crypto_perpetual_ethusdt
+            .truncate_to_limits(Quantity::from(quantity), Price::from("1.00"));
+        assert_eq!(quantity, Quantity::from(expected));
+    }
+
+    #[rstest]
+    fn test_truncate_to_limits_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        // Inverse notional limits in the quote currency cap the number of contracts directly
+        let quantity =
+            xbtusd_bitmex.truncate_to_limits(Quantity::from(20_000_000), Price::from("50000.0"));
+        assert_eq!(quantity, Quantity::from(10_000_000));
+    }
+
+    #[rstest]
+    fn test_truncate_to_limits_at_precision_9() {
+        // 17 significant digits, beyond the precision of an `f64`
+        let max_quantity = Quantity::from_raw(12_345_678_123_456_789, 9);
+        let instrument = builder_with_limits()
+            .size_precision(9)
+            .size_increment(Quantity::from_raw(1, 9))
+            .max_quantity(max_quantity)
+            .build()
+            .unwrap();
+        let quantity = instrument.truncate_to_limits(
+            Quantity::from_raw(20_000_000_000_000_000, 9),
+            Price::from("100.0"),
+        );
+        assert_eq!(quantity, max_quantity);
+    }
+
+    #[rstest]
+    #[case("1.000", "2.500", "3000.00 USDT")] // Increase
+    #[case("2.500", "1.000", "-3000.00 USDT")] // Decrease
+    #[case("1.000", "1.000", "0.00 USDT")]
+    fn test_margin_delta_linear(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] old_qty: &str,
+        #[case] new_qty: &str,
+        #[case] expected: &str,
+    ) {
+        // margin_init 1.0 at 2,000 USDT per ETH
+        let delta = crypto_perpetual_ethusdt.margin_delta(
+            Quantity::from(old_qty),
+            Quantity::from(new_qty),
+            Price::from("2000.00"),
+        );
+        assert_eq!(delta, Money::from(expected));
+    }
+
+    #[rstest]
+    fn test_margin_delta_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        // (20,000 - 10,000) contracts / 50,000 * 0.01 margin_init = 0.002 BTC
+        let delta = xbtusd_bitmex.margin_delta(
+            Quantity::from(10_000),
+            Quantity::from(20_000),
+            Price::from("50000.0"),
+        );
+        assert_eq!(delta, Money::from("0.00200000 BTC"));
+    }
+
+    #[rstest]
+    #[case("2100.00")] // Upper edge of band
+    #[case("2099.99")]
+    #[case("1900.01")]
+    #[case("1900.00")] // Lower edge of band
+    fn test_check_price_band_inside(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] price: &str,
+    ) {
+        let result = crypto_perpetual_ethusdt.check_price_band(
+            Price::from(price),
+            Price::from("2000.00"),
+            dec!(0.05),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[rstest]
+    #[case("2100.01")]
+    #[case("1899.99")]
+    fn test_check_price_band_outside(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] price: &str,
+    ) {
+        let result = crypto_perpetual_ethusdt.check_price_band(
+            Price::from(price),
+            Price::from("2000.00"),
+            dec!(0.05),
+        );
+        assert_eq!(
+            result,
+            Err(InstrumentError::PriceOutsideBand {
+                price: Price::from(price),
+                reference: Price::from("2000.00"),
+                tolerance: dec!(0.05),
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Invalid price: {price} was outside 5% of reference price 2000.00")
+        );
+    }
+
+    #[rstest]
+    fn test_check_price_band_absolute_limit(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        // Within the band but above `max_price`
+        let result = crypto_perpetual_ethusdt.check_price_band(
+            Price::from("15000.01"),
+            Price::from("15000.00"),
+            dec!(0.05),
+        );
+        assert_eq!(
+            result,
+            Err(InstrumentError::MaxPriceExceeded(
+                Price::from("15000.01"),
+                Price::from("15000.00")
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_validate_price_precision_valid(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        assert!(crypto_perpetual_ethusdt
+            .validate_price_precision(Price::from("2000.01"))
+            .is_ok());
+        assert!(crypto_perpetual_ethusdt
+            .validate_price_precision(Price::from("2000.1"))
+            .is_ok());
+        assert!(xbtusd_bitmex
+            .validate_price_precision(Price::from("10000.5"))
+            .is_ok());
+    }
+
+    #[rstest]
+    fn test_validate_price_precision_over_precise(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let result = crypto_perpetual_ethusdt.validate_price_precision(Price::from("2000.001"));
+        assert_eq!(
+            result,
+            Err(InstrumentError::PricePrecisionExceeded(
+                Price::from("2000.001"),
+                2
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_validate_price_precision_off_tick(xbtusd_bitmex: CryptoPerpetual) {
+        let result = xbtusd_bitmex.validate_price_precision(Price::from("10000.3"));
+        assert_eq!(
+            result,
+            Err(InstrumentError::PriceNotOnIncrement(
+                Price::from("10000.3"),
+                Price::from("0.5")
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case(Currency::JPY(), "1234567", "247 JPY")]
+    #[case(Currency::USD(), "12345.67", "2.47 USD")]
+    fn test_fee_display_uses_currency_precision(
+        #[case] quote: Currency,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        let mut instrument = CryptoPerpetual::with_defaults(
+            InstrumentId::from(format!("BTC{quote}-PERP.SIM").as_str()),
+            Currency::BTC(),
+            quote,
+        );
+        instrument.taker_fee = dec!(0.0004);
+
+        let fee = instrument.calculate_fee(
+            Quantity::from("0.50000000"),
+            Price::from(price),
+            LiquiditySide::Taker,
+        );
+
+        assert_eq!(fee.currency, quote);
+        assert_eq!(fee.to_string(), expected);
+        assert_eq!(
+            serde_json::to_string(&fee).unwrap(),
+            format!("\"{expected}\"")
+        );
+    }
+
+    #[rstest]
+    fn test_validate_order_valid(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let result = crypto_perpetual_ethusdt
+            .validate_order(Quantity::from("1.000"), Price::from("2000.00"));
+        assert!(result.is_ok());
+    }
+
+    #[rstest]
+    #[case(
+        "10000.001",
+        "2000.00",
+        InstrumentError::MaxQuantityExceeded(
+            Quantity::from("10000.001"),
+            Quantity::from("10000.0")
+        )
+    )]
+    #[case(
+        "0.000",
+        "2000.00",
+        InstrumentError::MinQuantityNotMet(Quantity::from("0.000"), Quantity::from("0.001"))
+    )]
+    #[case(
+        "1.000",
+        "15000.01",
+        InstrumentError::MaxPriceExceeded(Price::from("15000.01"), Price::from("15000.00"))
+    )]
+    #[case(
+        "1.000",
+        "0.99",
+        InstrumentError::MinPriceNotMet(Price::from("0.99"), Price::from("1.0"))
+    )]
+    #[case(
+        "0.004",
+        "2000.00",
+        InstrumentError::MinNotionalNotMet(Money::from("8.00 USDT"), Money::from("10.00 USDT"))
+    )]
+    fn test_validate_order_violations(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] quantity: &str,
+        #[case] price: &str,
+        #[case] expected: InstrumentError,
+    ) {
+        let result =
+            crypto_perpetual_ethusdt.validate_order(Quantity::from(quantity), Price::from(price));
+        assert_eq!(result, Err(expected));
+    }
+
+    #[rstest]
+    fn test_validate_order_max_notional_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        let result =
+            xbtusd_bitmex.validate_order(Quantity::from(10_000_001), Price::from("50000.0"));
+        assert_eq!(
+            result,
+            Err(InstrumentError::MaxNotionalExceeded(
+                Money::from("10000001 USD"),
+                Money::from("10000000 USD"),
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_validate_order_inverse_base_currency_notional(xbtusd_bitmex: CryptoPerpetual) {
+        let mut instrument = xbtusd_bitmex;
+        instrument.max_notional = Some(Money::from("100.00000000 BTC"));
+        instrument.min_notional = Some(Money::from("0.00010000 BTC"));
+
+        // 1,000 contracts / 50,000 = 0.02 BTC
+        let result = instrument.validate_order(Quantity::from(1_000), Price::from("50000.0"));
+        assert!(result.is_ok());
+
+        // 1 contract / 50,000 = 0.00002 BTC
+        let result = instrument.validate_order(Quantity::from(1), Price::from("50000.0"));
+        assert_eq!(
+            result,
+            Err(InstrumentError::MinNotionalNotMet(
+                Money::from("0.00002000 BTC"),
+                Money::from("0.00010000 BTC"),
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_validate_order_inverse_inconsistent_notional_currency(xbtusd_bitmex: CryptoPerpetual) {
+        let mut instrument = xbtusd_bitmex;
+        instrument.max_notional = Some(Money::from("1000000.00 EUR"));
+
+        let result = instrument.validate_order(Quantity::from(1_000), Price::from("50000.0"));
+        let err = result.unwrap_err();
+        assert_eq!(
+            err,
+            InstrumentError::InvalidNotionalCurrency(Money::from("1000000.00 EUR"))
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid notional limit 1000000.00 EUR: the instrument cannot compute a notional in EUR"
+        );
+    }
+
+    #[rstest]
+    fn test_validate_order_linear_base_currency_notional(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.min_notional = Some(Money::from("0.01000000 ETH"));
+
+        let result = instrument.validate_order(Quantity::from("1.000"), Price::from("2000.00"));
+        assert_eq!(
+            result,
+            Err(InstrumentError::InvalidNotionalCurrency(Money::from(
+                "0.01000000 ETH"
+            )))
+        );
+    }
+
+    #[rstest]
+    fn test_with_defaults() {
+        let instrument = CryptoPerpetual::with_defaults(
+            InstrumentId::from("ETHUSD-PERP.SIM"),
+            Currency::ETH(),
+            Currency::USD(),
+        );
+
+        assert_eq!(instrument.raw_symbol, Symbol::from("ETHUSD-PERP"));
+        assert_eq!(instrument.settlement_currency, Currency::USD());
+        assert!(!instrument.is_inverse);
+        assert_eq!(instrument.price_precision, 2);
+        assert_eq!(instrument.price_increment, Price::from("0.01"));
+        assert_eq!(instrument.size_precision, 8);
+        assert_eq!(instrument.size_increment, Quantity::from("0.00000001"));
+        assert_eq!(instrument.maker_fee, Decimal::ZERO);
+        assert_eq!(instrument.taker_fee, Decimal::ZERO);
+    }
+
+    #[rstest]
+    fn test_builder() {
+        let instrument = CryptoPerpetualBuilder::new()
+            .id(InstrumentId::from("ETHUSDT-PERP.BINANCE"))
+            .base_currency(Currency::from("ETH"))
+            .quote_currency(Currency::from("USDT"))
+            .settlement_currency(Currency::from("USDT"))
+            .price_precision(2)
+            .size_precision(3)
+            .price_increment(Price::from("0.01"))
+            .size_increment(Quantity::from("0.001"))
+            .maker_fee(dec!(0.0002))
+            .taker_fee(dec!(0.0004))
+            .build()
+            .unwrap();
+
+        assert_eq!(instrument.raw_symbol, Symbol::from("ETHUSDT-PERP"));
+        assert_eq!(instrument.maker_fee, dec!(0.0002));
+        assert_eq!(instrument.taker_fee, dec!(0.0004));
+        assert_eq!(instrument.margin_init, Decimal::ZERO);
+        assert_eq!(instrument.lot_size, Quantity::from(1));
+        assert_eq!(instrument.max_quantity, None);
+        assert_eq!(instrument.min_notional, None);
+    }
+
+    #[rstest]
+    fn test_builder_fees_bps() {
+        let instrument = CryptoPerpetualBuilder::new()
+            .id(InstrumentId::from("BTCUSDT-PERP.SIM"))
+            .base_currency(Currency::BTC())
+            .quote_currency(Currency::USDT())
+            .settlement_currency(Currency::USDT())
+            .price_precision(1)
+            .size_precision(3)
+            .price_increment(Price::from("0.1"))
+            .size_increment(Quantity::from("0.001"))
+            .maker_fee_bps(BasisPoints::new(dec!(-2.5)))
+            .taker_fee_bps(BasisPoints::new(dec!(10)))
+            .build()
+            .unwrap();
+
+        assert_eq!(instrument.maker_fee, dec!(-0.00025));
+        assert_eq!(instrument.taker_fee, dec!(0.001));
+    }
+
+    #[rstest]
+    fn test_builder_missing_increment() {
+        let result = CryptoPerpetualBuilder::new()
+            .id(InstrumentId::from("ETHUSDT-PERP.BINANCE"))
+            .base_currency(Currency::from("ETH"))
+            .quote_currency(Currency::from("USDT"))
+            .settlement_currency(Currency::from("USDT"))
+            .price_precision(2)
+            .size_precision(3)
+            .price_increment(Price::from("0.01"))
+            .build();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Missing required fields for `CryptoPerpetual`: size_increment"
+        );
+    }
+
+    #[rstest]
+    fn test_builder_empty() {
+        let result = CryptoPerpetualBuilder::new().build();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Missing required fields for `CryptoPerpetual`: id, base_currency, quote_currency, \
+             settlement_currency, price_precision, size_precision, price_increment, size_increment"
+        );
+    }
+
+    #[rstest]
+    fn test_builder_invalid_returns_error() {
+        // Mismatched price precision fails validation rather than panicking
+        let result = builder_with_limits().price_precision(2).build();
+
+        let err = result.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<InstrumentError>(),
+            Some(InstrumentError::PrecisionMismatch { .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_deserialize_legacy_json_without_funding() {
+        // Snapshot written before the funding fields were added
+        let json = r#"{
+            "id": "ETHUSDT-PERP.BINANCE",
+            "raw_symbol": "ETHUSDT",
+            "base_currency": "ETH",
+            "quote_currency": "USDT",
+            "settlement_currency": "USDT",
+            "is_inverse": false,
+            "price_precision": 2,
+            "size_precision": 3,
+            "price_increment": "0.01",
+            "size_increment": "0.001",
+            "multiplier": "1",
+            "lot_size": "1",
+            "margin_init": "1.0",
+            "margin_maint": "0.35",
+            "maker_fee": "0.0002",
+            "taker_fee": "0.0004",
+            "max_quantity": "10000.000",
+            "min_quantity": "0.001",
+            "max_notional": null,
+            "min_notional": "10.00 USDT",
+            "max_price": "15000.00",
+            "min_price": "1.0",
+            "ts_event": 0,
+            "ts_init": 0
+        }"#;
+
+        let instrument: CryptoPerpetual = serde_json::from_str(json).unwrap();
+
+        assert_eq!(instrument.funding_rate, None);
+        assert_eq!(instrument.funding_interval_ns, None);
+        assert!(instrument.equals_full(&crypto_perpetual_ethusdt()));
+    }
+
+    #[rstest]
+    fn test_decimal_fields_round_trip_as_strings(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.maker_fee = dec!(0.00075);
+        instrument.taker_fee = dec!(0.00075);
+
+        let value = serde_json::to_value(instrument).unwrap();
+        assert_eq!(value["maker_fee"], "0.00075");
+        assert_eq!(value["taker_fee"], "0.00075");
+        assert_eq!(value["margin_init"], "1");
+        assert_eq!(value["margin_maint"], "0.35");
+
+        let json = serde_json::to_string(&instrument).unwrap();
+        let deserialized: CryptoPerpetual = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.maker_fee, dec!(0.00075));
+        assert_eq!(deserialized.taker_fee.to_string(), "0.00075");
+        assert!(deserialized.equals_full(&instrument));
+    }
+
+    #[rstest]
+    fn test_serialize_omits_precision_fields(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let value = serde_json::to_value(crypto_perpetual_ethusdt).unwrap();
+
+        assert!(value.get("price_precision").is_none());
+        assert!(value.get("size_precision").is_none());
+        assert_eq!(value["price_increment"], "0.01");
+    }
+
+    #[rstest]
+    fn test_deserialize_derives_precision_from_increments(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut value = serde_json::to_value(crypto_perpetual_ethusdt).unwrap();
+        value["price_increment"] = "0.0001".into();
+        value["size_increment"] = "0.1".into();
+
+        let json = serde_json::to_string(&value).unwrap();
+        let instrument: CryptoPerpetual = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(instrument.price_precision, 4);
+        assert_eq!(instrument.size_precision, 1);
+    }
+
+    #[rstest]
+    fn test_deserialize_mismatched_precision(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut value = serde_json::to_value(crypto_perpetual_ethusdt).unwrap();
+        value["price_precision"] = 3.into();
+
+        let json = serde_json::to_string(&value).unwrap();
+        let result = serde_json::from_str::<CryptoPerpetual>(&json);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("`price_precision` 3 did not match `price_increment.precision` 2"));
+    }
+
+    #[rstest]
+    #[case("price_increment", "0".into(), "`price_increment` 0 was not positive")]
+    #[case("multiplier", "0".into(), "`multiplier` 0 was not positive")]
+    #[case(
+        "min_quantity",
+        "1000000.000".into(),
+        "`min_quantity` was greater than `max_quantity`"
+    )]
+    #[case("funding_interval_ns", 0.into(), "`funding_interval_ns` 0 was not positive")]
+    fn test_deserialize_invalid_snapshot(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] field: &str,
+        #[case] invalid: serde_json::Value,
+        #[case] expected: &str,
+    ) {
+        let mut value = serde_json::to_value(crypto_perpetual_ethusdt).unwrap();
+        value["max_quantity"] = "10000.000".into();
+        value[field] = invalid;
+
+        let json = serde_json::to_string(&value).unwrap();
+        let result = serde_json::from_str::<CryptoPerpetual>(&json);
+
+        assert!(result.unwrap_err().to_string().contains(expected));
+    }
+
+    #[rstest]
+    fn test_serde_json_round_trip(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut instrument = crypto_perpetual_ethusdt;
+        instrument.funding_rate = Some(dec!(0.0001));
+        instrument.funding_interval_ns = Some(UnixNanos::from(28_800_000_000_000));
+
+        let json = serde_json::to_string(&instrument).unwrap();
+        let deserialized: CryptoPerpetual = serde_json::from_str(&json).unwrap();
+
+        assert!(deserialized.equals_full(&instrument));
+    }
+
+    fn builder_btcusdt(settlement_currency: Currency) -> CryptoPerpetualBuilder {
+        let mut builder = CryptoPerpetualBuilder::new();
+        builder
+            .id(InstrumentId::from("BTCUSDT-PERP.SIM"))
+            .base_currency(Currency::BTC())
+            .quote_currency(Currency::USDT())
+            .settlement_currency(settlement_currency)
+            .price_precision(1)
+            .size_precision(3)
+            .price_increment(Price::from("0.1"))
+            .size_increment(Quantity::from("0.001"));
+        builder
+    }
+
+    #[rstest]
+    fn test_new_checked_third_settlement_currency_permissive() {
+        let instrument = builder_btcusdt(Currency::USDC()).build().unwrap();
+        assert_eq!(instrument.settlement_currency, Currency::USDC());
+    }
+
+    #[rstest]
+    #[case(Currency::BTC())]
+    #[case(Currency::USDT())]
+    fn test_new_checked_strict_valid_settlement_currency(#[case] settlement_currency: Currency) {
+        let instrument = builder_btcusdt(settlement_currency).build_strict().unwrap();
+        assert_eq!(instrument.settlement_currency, settlement_currency);
+    }
+
+    #[rstest]
+    fn test_new_checked_strict_third_settlement_currency() {
+        let result = builder_btcusdt(Currency::USDC()).build_strict();
+        let err = result.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<InstrumentError>(),
+            Some(InstrumentError::InvalidSettlementCurrency { settlement_currency, .. })
+                if *settlement_currency == Currency::USDC()
+        ));
+        assert!(err
+            .to_string()
+            .contains("`settlement_currency` USDC was neither"));
+    }
+
+    #[rstest]
+    fn test_multiplier_default(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        assert_eq!(crypto_perpetual_ethusdt.multiplier(), Quantity::from(1));
+    }
+
+    #[rstest]
+    fn test_notional_with_multiplier(xbtusd_bitmex: CryptoPerpetual) {
+        let mut instrument = xbtusd_bitmex;
+        instrument.multiplier = Quantity::from(100);
+        let quantity = Quantity::from(10);
+        let price = Price::from("50000.0");
+
+        let notional = instrument.calculate_notional_value(quantity, price, None);
+        let base_notional = xbtusd_bitmex.calculate_notional_value(quantity, price, None);
+
+        assert_eq!(instrument.multiplier(), Quantity::from(100));
+        assert_eq!(notional, Money::from("0.02000000 BTC"));
+        assert_eq!(
+            notional.as_decimal(),
+            base_notional.as_decimal() * dec!(100)
+        );
+        assert_eq!(
+            instrument.calculate_margin_init(quantity, price),
+            Money::from("0.00020000 BTC")
+        );
+    }
+
+    #[rstest]
+    fn test_new_checked_with_multiplier() {
+        let instrument = CryptoPerpetualBuilder::new()
+            .id(InstrumentId::from("BTCUSD-PERP.SIM"))
+            .base_currency(Currency::BTC())
+            .quote_currency(Currency::USD())
+            .settlement_currency(Currency::USD())
+            .price_precision(1)
+            .size_precision(0)
+            .price_increment(Price::from("0.1"))
+            .size_increment(Quantity::from(1))
+            .multiplier(Quantity::from(100))
+            .build()
+            .unwrap();
+
+        let notional =
+            instrument.calculate_notional_value(Quantity::from(2), Price::from("50000.0"), None);
+
+        assert_eq!(notional, Money::from("10000000 USD"));
+    }
+
+    #[rstest]
+    fn test_new_checked_zero_multiplier() {
+        let result = CryptoPerpetualBuilder::new()
+            .id(InstrumentId::from("BTCUSD-PERP.SIM"))
+            .base_currency(Currency::BTC())
+            .quote_currency(Currency::USD())
+            .settlement_currency(Currency::USD())
+            .price_precision(1)
+            .size_precision(0)
+            .price_increment(Price::from("0.1"))
+            .size_increment(Quantity::from(1))
+            .multiplier(Quantity::from(0))
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_msgpack_round_trip(xbtusd_bitmex: CryptoPerpetual) {
+        let mut instrument = xbtusd_bitmex;
+        instrument.funding_rate = Some(dec!(0.0001));
+        instrument.funding_interval_ns = Some(UnixNanos::from(28_800_000_000_000));
+
+        let serialized = instrument.as_msgpack_bytes().unwrap();
+        let deserialized = CryptoPerpetual::from_msgpack_bytes(serialized.as_ref()).unwrap();
+
+        assert!(deserialized.equals_full(&instrument));
+    }
+
+    #[rstest]
+    fn test_msgpack_invalid_bytes(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let serialized = crypto_perpetual_ethusdt.as_msgpack_bytes().unwrap();
+        let truncated = &serialized[..serialized.len() / 2];
+
+        assert!(CryptoPerpetual::from_msgpack_bytes(truncated).is_err());
+    }
+
+    #[rstest]
+    fn test_new_with_funding() {
+        let interval = UnixNanos::from_secs(8 * 60 * 60);
+        let perpetual = builder_btcusdt(Currency::USDT())
+            .funding_rate(dec!(0.0001))
+            .funding_interval_ns(interval)
+            .build()
+            .unwrap();
+        assert_eq!(perpetual.funding_rate(), Some(dec!(0.0001)));
+        assert_eq!(perpetual.funding_interval_ns(), Some(interval));
+    }
+
+    #[rstest]
+    fn test_new_without_funding(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        assert_eq!(crypto_perpetual_ethusdt.funding_rate(), None);
+        assert_eq!(crypto_perpetual_ethusdt.funding_interval_ns(), None);
+    }
+
+    #[rstest]
+    #[case(8 * 60 * 60, "10.00 USDT")] // Full interval
+    #[case(4 * 60 * 60, "5.00 USDT")] // Half interval
+    #[case(-8 * 60 * 60, "-10.00 USDT")]
+    fn test_accrued_funding(#[case] elapsed_secs: i64, #[case] expected: &str) {
+        let interval = UnixNanos::from_secs(8 * 60 * 60);
+        let perpetual = builder_btcusdt(Currency::USDT())
+            .funding_rate(dec!(0.0001))
+            .funding_interval_ns(interval)
+            .build()
+            .unwrap();
+
+        let funding =
+            perpetual.accrued_funding(Money::from("100000 USDT"), elapsed_secs * 1_000_000_000);
+
+        assert_eq!(funding, Some(Money::from(expected)));
+    }
+
+    #[rstest]
+    #[case(None, Some(UnixNanos::from_secs(8 * 60 * 60)))]
+    #[case(Some(dec!(0.0001)), None)]
+    #[case(None, None)]
+    fn test_accrued_funding_none(
+        #[case] funding_rate: Option<Decimal>,
+        #[case] funding_interval_ns: Option<UnixNanos>,
+    ) {
+        let mut builder = builder_btcusdt(Currency::USDT());
+        if let Some(funding_rate) = funding_rate {
+            builder.funding_rate(funding_rate);
+        }
+        if let Some(funding_interval_ns) = funding_interval_ns {
+            builder.funding_interval_ns(funding_interval_ns);
+        }
+        let perpetual = builder.build().unwrap();
+
+        let funding = perpetual.accrued_funding(Money::from("100000 USDT"), 1_000_000_000);
+
+        assert_eq!(funding, None);
+    }
+
+    #[rstest]
+    fn test_new_with_zero_funding_interval() {
+        let result = builder_btcusdt(Currency::USDT())
+            .funding_rate(dec!(0.0001))
+            .funding_interval_ns(UnixNanos::default())
+            .build();
+        assert_eq!(
+            result.unwrap_err().downcast::<InstrumentError>().unwrap(),
+            InstrumentError::NonPositiveValue {
+                instrument_id: InstrumentId::from("BTCUSDT-PERP.SIM"),
+                field: "funding_interval_ns",
+                value: "0".to_string(),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::precision_mismatch(
+        builder_with_limits().price_precision(2).clone(),
+        |e: &InstrumentError| matches!(
+            e,
+            InstrumentError::PrecisionMismatch { field: "price_precision", .. }
+        )
+    )]
+    #[case::non_positive_increment(
+        builder_with_limits().size_increment(Quantity::from(0)).clone(),
+        |e: &InstrumentError| matches!(
+            e,
+            InstrumentError::NonPositiveIncrement { field: "size_increment", .. }
+        )
+    )]
+    #[case::non_positive_value(
+        builder_with_limits().multiplier(Quantity::from(0)).clone(),
+        |e: &InstrumentError| matches!(
+            e,
+            InstrumentError::NonPositiveValue { field: "multiplier", .. }
+        )
+    )]
+    #[case::invalid_limit(
+        builder_with_limits().max_quantity(Quantity::from(0)).clone(),
+        |e: &InstrumentError| matches!(
+            e,
+            InstrumentError::InvalidLimit { field: "max_quantity", .. }
+        )
+    )]
+    #[case::inverted_limits(
+        builder_with_limits()
+            .min_price(Price::from("2.0"))
+            .max_price(Price::from("1.0"))
+            .clone(),
+        |e: &InstrumentError| matches!(
+            e,
+            InstrumentError::InvertedLimits { field: "price", .. }
+        )
+    )]
+    fn test_new_checked_error_variants(
+        #[case] builder: CryptoPerpetualBuilder,
+        #[case] is_expected: fn(&InstrumentError) -> bool,
+    ) {
+        let err = builder.build().unwrap_err();
+        let err = err.downcast_ref::<InstrumentError>().unwrap();
+        assert!(is_expected(err), "Unexpected error {err}");
+    }
+
+    #[rstest]
+    #[case(Money::from("-1 USD"), None, "max_notional")]
+    #[case(Money::from("1000 USD"), Some(Money::from("-1 USD")), "min_notional")]
+    fn test_new_checked_negative_notional_limit(
+        #[case] max_notional: Money,
+        #[case] min_notional: Option<Money>,
+        #[case] field: &'static str,
+    ) {
+        let mut builder = builder_with_limits();
+        builder.max_notional(max_notional);
+        if let Some(min_notional) = min_notional {
+            builder.min_notional(min_notional);
+        }
+
+        let err = builder.build().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<InstrumentError>().unwrap(),
+            &InstrumentError::InvalidLimit {
+                instrument_id: InstrumentId::from("BTCUSD-PERP.SIM"),
+                field,
+                value: "-1.00 USD".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Invalid instrument BTCUSD-PERP.SIM: `{field}` -1.00 USD was not a valid limit"
+            )
+        );
+    }
+
+    /// Spec for the `crypto_perpetual_ethusdt` stub, as formatted by the venue.
+    fn ethusdt_spec() -> PerpetualSpec {
+        PerpetualSpec {
+            instrument_id: "ETHUSDT-PERP.BINANCE".to_string(),
+            raw_symbol: "ETHUSDT".to_string(),
+            base_currency: "ETH".to_string(),
+            quote_currency: "USDT".to_string(),
+            settlement_currency: "USDT".to_string(),
+            is_inverse: false,
+            price_increment: "0.01000000".to_string(),
+            size_increment: "0.00100000".to_string(),
+            max_quantity: Some("10000".to_string()),
+            min_quantity: Some("0.001".to_string()),
+            min_notional: Some("10".to_string()),
+            max_price: Some("15000.00000000".to_string()),
+            min_price: Some("1.00".to_string()),
+            margin_init: Some("1.0".to_string()),
+            margin_maint: Some("0.35".to_string()),
+            maker_fee: Some("0.000200".to_string()),
+            taker_fee: Some("0.000400".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn test_from_spec(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument =
+            CryptoPerpetual::from_spec(ethusdt_spec(), &CurrencyResolver::new()).unwrap();
+
+        assert_eq!(instrument.price_precision, 2);
+        assert_eq!(instrument.size_precision, 3);
+        assert_eq!(instrument.max_price.unwrap().to_string(), "15000.00");
+        assert_eq!(instrument.min_notional, Some(Money::from("10 USDT")));
+        assert!(instrument.equals_full(&crypto_perpetual_ethusdt));
+    }
+
+    #[rstest]
+    fn test_from_spec_unknown_currency() {
+        let mut spec = ethusdt_spec();
+        spec.settlement_currency = "NOT_A_CODE".to_string();
+
+        let result = CryptoPerpetual::from_spec(spec, &CurrencyResolver::new());
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Unknown currency: NOT_A_CODE"
+        );
+    }
+
+    #[rstest]
+    fn test_from_spec_invalid_decimal() {
+        let mut spec = ethusdt_spec();
+        spec.taker_fee = Some("four bps".to_string());
+
+        let result = CryptoPerpetual::from_spec(spec, &CurrencyResolver::new());
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("Invalid `taker_fee` 'four bps'"));
+    }
+
+    #[rstest]
+    #[case(
+        "min_quantity",
+        "Invalid `min_quantity` 0.0015, not representable at precision 3"
+    )]
+    #[case(
+        "max_price",
+        "Invalid `max_price` 15000.005, not representable at precision 2"
+    )]
+    fn test_from_spec_off_precision(#[case] field: &str, #[case] expected: &str) {
+        let mut spec = ethusdt_spec();
+        match field {
+            "min_quantity" => spec.min_quantity = Some("0.0015".to_string()),
+            _ => spec.max_price = Some("15000.005".to_string()),
+        }
+
+        let result = CryptoPerpetual::from_spec(spec, &CurrencyResolver::new());
+
+        assert_eq!(result.unwrap_err().to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_from_spec_exact_at_precision_9() {
+        let mut spec = ethusdt_spec();
+        spec.size_increment = "0.000000001".to_string();
+        spec.min_quantity = Some("12345678.123456789".to_string());
+        spec.max_quantity = None;
+
+        let instrument = CryptoPerpetual::from_spec(spec, &CurrencyResolver::new()).unwrap();
+
+        assert_eq!(
+            instrument.min_quantity,
+            Some(Quantity::from_raw(12_345_678_123_456_789, 9))
+        );
+    }
+
+    fn builder_with_limits() -> CryptoPerpetualBuilder {
+        let mut builder = CryptoPerpetualBuilder::new();
+        builder
+            .id(InstrumentId::from("BTCUSD-PERP.SIM"))
+            .base_currency(Currency::BTC())
+            .quote_currency(Currency::USD())
+            .settlement_currency(Currency::USD())
+            .price_precision(1)
+            .size_precision(0)
+            .price_increment(Price::from("0.1"))
+            .size_increment(Quantity::from(1));
+        builder
+    }
+
+    #[rstest]
+    #[case("quantity")]
+    #[case("price")]
+    #[case("notional")]
+    fn test_new_checked_inverted_limits(#[case] field: &'static str) {
+        let mut builder = builder_with_limits();
+        match field {
+            "quantity" => builder
+                .min_quantity(Quantity::from(100))
+                .max_quantity(Quantity::from(10)),
+            "price" => builder
+                .min_price(Price::from("100000.0"))
+                .max_price(Price::from("0.5")),
+            _ => builder
+                .min_notional(Money::from("1000 USD"))
+                .max_notional(Money::from("10 USD")),
+        };
+
+        let err = builder.build().unwrap_err();
+        let err = err.downcast_ref::<InstrumentError>().unwrap();
+        assert_eq!(
+            err,
+            &InstrumentError::InvertedLimits {
+                instrument_id: InstrumentId::from("BTCUSD-PERP.SIM"),
+                field,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Invalid instrument BTCUSD-PERP.SIM: `min_{field}` was greater than `max_{field}`"
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_new_checked_valid_limits() {
+        let instrument = builder_with_limits()
+            .min_quantity(Quantity::from(10))
+            .max_quantity(Quantity::from(10)) // Equal bounds are valid
+            .min_price(Price::from("0.5"))
+            .max_price(Price::from("100000.0"))
+            .min_notional(Money::from("10 USD"))
+            .max_notional(Money::from("1000 USD"))
+            .build();
+        assert!(instrument.is_ok());
+
+        // Only one side present is not checked
+        let instrument = builder_with_limits()
+            .min_quantity(Quantity::from(100))
+            .max_price(Price::from("0.5"))
+            .build();
+        assert!(instrument.is_ok());
+    }
+
+    #[rstest]
+    fn test_new_checked_normalizes_decimals() {
+        let instrument = builder_with_limits()
+            .maker_fee(dec!(0.0010))
+            .taker_fee(dec!(0.0020))
+            .funding_rate(dec!(0.000100))
+            .build()
+            .unwrap();
+        let rescaled = builder_with_limits()
+            .maker_fee(dec!(0.00100000))
+            .taker_fee(dec!(0.002))
+            .funding_rate(dec!(0.0001000000))
+            .build()
+            .unwrap();
+
+        assert_eq!(instrument.maker_fee.scale(), rescaled.maker_fee.scale());
+        assert_eq!(instrument.taker_fee.to_string(), "0.002");
+        assert!(instrument.equals_full(&rescaled));
+        assert_eq!(instrument.spec_hash(), rescaled.spec_hash());
+        assert_eq!(
+            serde_json::to_string(&instrument).unwrap(),
+            serde_json::to_string(&rescaled).unwrap()
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(100))]
+
+        #[test]
+        fn prop_arbitrary_instrument_passes_new_checked(
+            bytes in prop::collection::vec(any::<u8>(), 256),
+        ) {
+            use arbitrary::{Arbitrary, Unstructured};
+
+            let mut u = Unstructured::new(&bytes);
+            let instrument = CryptoPerpetual::arbitrary(&mut u).unwrap();
+
+            let checked = CryptoPerpetual::new_checked(
+                instrument.id,
+                instrument.raw_symbol,
+                instrument.base_currency,
+                instrument.quote_currency,
+                instrument.settlement_currency,
+                instrument.is_inverse,
+                instrument.price_precision,
+                instrument.size_precision,
+                instrument.price_increment,
+                instrument.size_increment,
+                Some(instrument.multiplier),
+                Some(instrument.lot_size),
+                instrument.max_quantity,
+                instrument.min_quantity,
+                instrument.max_notional,
+                instrument.min_notional,
+                instrument.max_price,
+                instrument.min_price,
+                Some(instrument.margin_init),
+                Some(instrument.margin_maint),
+                Some(instrument.maker_fee),
+                Some(instrument.taker_fee),
+                instrument.funding_rate,
+                instrument.funding_interval_ns,
+                instrument.ts_event,
+                instrument.ts_init,
+            );
+            prop_assert_eq!(checked.map(|i| i.equals_full(&instrument)), Ok(true));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_inverse_notional_recovers_quantity(
+            contracts in 1i64..=1_000_000,
+            ticks in 2_000i64..=400_000,
+        ) {
+            let instrument = xbtusd_bitmex();
+            let quantity = Quantity::from(contracts);
+            let price = Price::from_raw(ticks * instrument.price_increment.raw, 1);
+
+            let notional = instrument.calculate_notional_value(quantity, price, None);
+            let recovered = notional.as_decimal() * price.as_decimal();
+
+            prop_assert_eq!(notional.currency, Currency::BTC());
+            prop_assert!((recovered - quantity.as_decimal()).abs() <= instrument.size_increment.as_decimal());
+        }
+
+        #[test]
+        fn prop_linear_notional_recovers_quantity(
+            lots in 1u64..=10_000_000,
+            ticks in 100i64..=1_500_000,
+        ) {
+            let instrument = crypto_perpetual_ethusdt();
+            let quantity = Quantity::from_raw(lots * instrument.size_increment.raw, 3);
+            let price = Price::from_raw(ticks * instrument.price_increment.raw, 2);
+
+            let notional = instrument.calculate_notional_value(quantity, price, None);
+            let recovered = notional.as_decimal() / price.as_decimal();
+
+            prop_assert_eq!(notional.currency, Currency::USDT());
+            prop_assert!((recovered - quantity.as_decimal()).abs() <= instrument.size_increment.as_decimal());
+        }
     }
 }
@@ -0,0 +1,108 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Errors associated with instrument definitions and order validation.
+
+use rust_decimal::Decimal;
+
+use crate::{
+    identifiers::InstrumentId,
+    types::{Currency, Money, Price, Quantity},
+};
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum InstrumentError {
+    #[error("Invalid quantity: {0} was greater than `max_quantity` {1}")]
+    MaxQuantityExceeded(Quantity, Quantity),
+    #[error("Invalid quantity: {0} was less than `min_quantity` {1}")]
+    MinQuantityNotMet(Quantity, Quantity),
+    #[error("Invalid price: {0} was greater than `max_price` {1}")]
+    MaxPriceExceeded(Price, Price),
+    #[error("Invalid price: {0} was less than `min_price` {1}")]
+    MinPriceNotMet(Price, Price),
+    #[error("Invalid price: {0} precision exceeded `price_precision` {1}")]
+    PricePrecisionExceeded(Price, u8),
+    #[error("Invalid price: {0} was not a multiple of `price_increment` {1}")]
+    PriceNotOnIncrement(Price, Price),
+    #[error(
+        "Invalid price: {price} was outside {}% of reference price {reference}",
+        (tolerance * Decimal::ONE_HUNDRED).normalize()
+    )]
+    PriceOutsideBand {
+        price: Price,
+        reference: Price,
+        tolerance: Decimal,
+    },
+    #[error("Invalid notional: {0} was greater than `max_notional` {1}")]
+    MaxNotionalExceeded(Money, Money),
+    #[error("Invalid notional: {0} was less than `min_notional` {1}")]
+    MinNotionalNotMet(Money, Money),
+    #[error(
+        "Invalid notional limit {0}: the instrument cannot compute a notional in {currency}",
+        currency = .0.currency
+    )]
+    InvalidNotionalCurrency(Money),
+    #[error(
+        "Invalid instrument {instrument_id}: `{field}` {precision} did not match `{increment_field}.precision` {increment_precision}"
+    )]
+    PrecisionMismatch {
+        instrument_id: InstrumentId,
+        field: &'static str,
+        precision: u8,
+        increment_field: &'static str,
+        increment_precision: u8,
+    },
+    #[error("Invalid instrument {instrument_id}: `{field}` {value} was not positive")]
+    NonPositiveIncrement {
+        instrument_id: InstrumentId,
+        field: &'static str,
+        value: String,
+    },
+    #[error("Invalid instrument {instrument_id}: `{field}` {value} was not positive")]
+    NonPositiveValue {
+        instrument_id: InstrumentId,
+        field: &'static str,
+        value: String,
+    },
+    #[error("Invalid instrument {instrument_id}: `{field}` {value} was not a valid limit")]
+    InvalidLimit {
+        instrument_id: InstrumentId,
+        field: &'static str,
+        value: String,
+    },
+    #[error("Invalid instrument {instrument_id}: `min_{field}` was greater than `max_{field}`")]
+    InvertedLimits {
+        instrument_id: InstrumentId,
+        field: &'static str,
+    },
+    #[error(
+        "Invalid instrument {instrument_id}: `settlement_currency` {settlement_currency} was neither the base currency {base_currency} nor the quote currency {quote_currency}"
+    )]
+    InvalidSettlementCurrency {
+        instrument_id: InstrumentId,
+        settlement_currency: Currency,
+        base_currency: Currency,
+        quote_currency: Currency,
+    },
+    #[error(
+        "Invalid instrument {instrument_id}: `related_instruments` contained {related} more than once"
+    )]
+    DuplicateRelatedInstrument {
+        instrument_id: InstrumentId,
+        related: InstrumentId,
+    },
+    #[error("Invalid `InstrumentAny` for {0}: not `{1}`")]
+    InvalidVariant(InstrumentId, &'static str),
+}
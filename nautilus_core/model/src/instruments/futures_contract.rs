@@ -359,11 +359,18 @@ impl Instrument for FuturesContract {
 mod tests {
     use rstest::rstest;
 
-    use crate::instruments::stubs::*;
+    use crate::instruments::{stubs::*, Instrument};
 
     #[rstest]
     fn test_equality() {
         let futures_contract = futures_contract_es(None, None);
         assert_eq!(futures_contract, futures_contract.clone());
     }
+
+    #[rstest]
+    fn test_is_cash_settled_unknown_for_class() {
+        let futures_contract = futures_contract_es(None, None);
+        assert!(!futures_contract.is_cash_settled());
+        assert!(futures_contract.is_physically_settled());
+    }
 }
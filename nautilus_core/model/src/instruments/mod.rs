@@ -22,6 +22,7 @@ pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
+pub mod error;
 pub mod futures_contract;
 pub mod futures_spread;
 pub mod options_contract;
@@ -31,8 +32,14 @@ pub mod synthetic;
 #[cfg(feature = "stubs")]
 pub mod stubs;
 
-use nautilus_core::nanos::UnixNanos;
-use rust_decimal::Decimal;
+use nautilus_core::{
+    correctness::{check_non_negative_f64, check_positive_i64},
+    nanos::UnixNanos,
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
 use rust_decimal_macros::dec;
 use ustr::Ustr;
 
@@ -40,14 +47,20 @@ use ustr::Ustr;
 pub use crate::instruments::{
     any::InstrumentAny, betting::BettingInstrument, binary_option::BinaryOption,
     crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
-    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
-    options_contract::OptionsContract, options_spread::OptionsSpread,
-    synthetic::SyntheticInstrument,
+    equity::Equity, error::InstrumentError, futures_contract::FuturesContract,
+    futures_spread::FuturesSpread, options_contract::OptionsContract,
+    options_spread::OptionsSpread, synthetic::SyntheticInstrument,
 };
+pub use crate::types::rounding::RoundingMode;
 use crate::{
-    enums::{AssetClass, InstrumentClass, OptionKind},
+    enums::{AssetClass, InstrumentClass, LiquiditySide, OptionKind, OrderSide, PositionSide},
     identifiers::{InstrumentId, Symbol, Venue},
-    types::{Currency, Money, Price, Quantity},
+    types::{
+        fixed::{f64_to_fixed_i64, f64_to_fixed_u64, FIXED_PRECISION},
+        round_money,
+        rounding::round_raw_to_increment,
+        Currency, Money, Price, Quantity, PRICE_MAX, QUANTITY_MAX,
+    },
 };
 
 pub trait Instrument: 'static + Send {
@@ -59,6 +72,15 @@ pub trait Instrument: 'static + Send {
     fn venue(&self) -> Venue {
         self.id().venue
     }
+
+    /// Returns the venue of the instrument as a string, for routing by venue.
+    ///
+    /// Instrument IDs follow the `SYMBOL.VENUE` convention, where the venue is the part after
+    /// the last `.` (so a symbol may itself contain dots, e.g. `BRK.B.XNYS` has venue `XNYS`).
+    /// This does not depend on the optional [`Instrument::exchange`] being populated.
+    fn venue_str(&self) -> String {
+        self.venue().to_string()
+    }
     fn raw_symbol(&self) -> Symbol;
     fn asset_class(&self) -> AssetClass;
     fn instrument_class(&self) -> InstrumentClass;
@@ -77,6 +99,16 @@ pub trait Instrument: 'static + Send {
     fn size_precision(&self) -> u8;
     fn price_increment(&self) -> Price;
     fn size_increment(&self) -> Quantity;
+    /// Returns the raw fixed-point value of the `price_increment` (tick size).
+    fn price_increment_raw(&self) -> i64 {
+        self.price_increment().raw
+    }
+
+    /// Returns the raw fixed-point value of the `size_increment`.
+    fn size_increment_raw(&self) -> u64 {
+        self.size_increment().raw
+    }
+
     fn multiplier(&self) -> Quantity;
     fn lot_size(&self) -> Option<Quantity>;
     fn max_quantity(&self) -> Option<Quantity>;
@@ -100,60 +132,1078 @@ pub trait Instrument: 'static + Send {
     fn taker_fee(&self) -> Decimal {
         dec!(0) // Temporary until separate fee models
     }
+
+    fn funding_rate(&self) -> Option<Decimal> {
+        None
+    }
+
+    fn funding_interval_ns(&self) -> Option<UnixNanos> {
+        None
+    }
     fn ts_event(&self) -> UnixNanos;
     fn ts_init(&self) -> UnixNanos;
 
-    /// Creates a new `Price` from the given `value` with the correct price precision for the instrument.
+    /// Returns whether the instrument is tradable as at `now`.
+    ///
+    /// This is when `now` is at or after the activation (if any), and before the expiration
+    /// (if any). Instruments without either (such as perpetuals) are always tradable.
+    fn is_tradable(&self, now: UnixNanos) -> bool {
+        let is_active = self
+            .activation_ns()
+            .is_none_or(|activation_ns| now >= activation_ns);
+        is_active && !self.is_expired(now)
+    }
+
+    /// Returns whether the instrument has expired as at `now` (inclusive of the expiration).
+    ///
+    /// Instruments without an expiration (such as perpetuals) never expire.
+    fn is_expired(&self, now: UnixNanos) -> bool {
+        self.expiration_ns()
+            .is_some_and(|expiration_ns| now >= expiration_ns)
+    }
+
+    /// Returns whether the instrument settles in cash rather than by delivery of the underlying.
+    ///
+    /// By default only swaps (including perpetuals) are taken to be cash-settled. For other
+    /// classes the settlement type depends on the contract and venue, so it is unknown from the
+    /// `instrument_class` alone and `false` is returned; instrument types which are always
+    /// cash-settled (such as crypto futures) override this.
+    fn is_cash_settled(&self) -> bool {
+        self.instrument_class() == InstrumentClass::Swap
+    }
+
+    /// Returns whether the instrument settles by delivery of the underlying, which is the
+    /// complement of [`Instrument::is_cash_settled`] (so includes an unknown settlement type).
+    fn is_physically_settled(&self) -> bool {
+        !self.is_cash_settled()
+    }
+
+    /// Creates a new `Price` from the given `value` rounded to the nearest multiple of the
+    /// instrument's `price_increment`, with the correct price precision for the instrument.
     fn make_price(&self, value: f64) -> Price {
-        Price::new(value, self.price_precision())
+        let raw = round_raw_to_increment(
+            i128::from(f64_to_fixed_i64(value, FIXED_PRECISION)),
+            i128::from(self.price_increment().raw),
+            RoundingMode::Nearest,
+        );
+        Price::from_raw(raw as i64, self.price_precision())
+    }
+
+    /// Appends a `Price` to `out` for each of the given `values`, rounded as per
+    /// [`Instrument::make_price`].
+    ///
+    /// The increment and precision are looked up once for the whole batch, and `out` is only
+    /// appended to (existing elements are kept), so the buffer can be reused across calls.
+    fn make_prices(&self, values: &[f64], out: &mut Vec<Price>) {
+        let increment = i128::from(self.price_increment().raw);
+        let precision = self.price_precision();
+        out.reserve(values.len());
+        out.extend(values.iter().map(|value| {
+            let raw = round_raw_to_increment(
+                i128::from(f64_to_fixed_i64(*value, FIXED_PRECISION)),
+                increment,
+                RoundingMode::Nearest,
+            );
+            Price::from_raw(raw as i64, precision)
+        }));
+    }
+
+    /// Returns the price `pct` away from `base`, computed as `base * (1 + pct)` with `Decimal`
+    /// and rounded to the nearest multiple of the `price_increment` (ties round to even).
+    ///
+    /// The `pct` is a fraction rather than a percentage (e.g. `0.01` for 1%), and may be negative
+    /// for levels below `base`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the resulting price is outside the representable range for `Price`.
+    fn price_at_pct(&self, base: Price, pct: Decimal) -> Price {
+        let value = base.as_decimal() * (Decimal::ONE + pct);
+        let raw = round_raw_to_increment(
+            decimal_to_raw(value),
+            i128::from(self.price_increment().raw),
+            RoundingMode::Nearest,
+        );
+        let raw = i64::try_from(raw).expect("Invalid raw value for price");
+        Price::from_raw(raw, self.price_precision())
+    }
+
+    /// Creates a new `Price` from the given `value` rounded down to a multiple of the
+    /// instrument's `price_increment` (the next valid bid price at or below `value`).
+    fn next_bid_price(&self, value: f64) -> Price {
+        let raw = round_raw_to_increment(
+            i128::from(f64_to_fixed_i64(value, FIXED_PRECISION)),
+            i128::from(self.price_increment().raw),
+            RoundingMode::Floor,
+        );
+        Price::from_raw(raw as i64, self.price_precision())
+    }
+
+    /// Creates a new `Price` from the given `value` rounded up to a multiple of the
+    /// instrument's `price_increment` (the next valid ask price at or above `value`).
+    fn next_ask_price(&self, value: f64) -> Price {
+        let raw = round_raw_to_increment(
+            i128::from(f64_to_fixed_i64(value, FIXED_PRECISION)),
+            i128::from(self.price_increment().raw),
+            RoundingMode::Ceil,
+        );
+        Price::from_raw(raw as i64, self.price_precision())
+    }
+
+    /// Returns the `reference` price moved by `ticks` multiples of the `price_increment` in the
+    /// passive direction for the given order `side` (down for buys, up for sells).
+    ///
+    /// The result is clamped to the `min_price` and `max_price` (when present). The
+    /// `reference` is not itself rounded to the price grid.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `side` is `NoOrderSide`.
+    fn improve_price(&self, reference: Price, side: OrderSide, ticks: u32) -> Price {
+        let ticks = i128::from(ticks);
+        match side {
+            OrderSide::Buy => shift_price_by_ticks(self, reference, -ticks),
+            OrderSide::Sell => shift_price_by_ticks(self, reference, ticks),
+            OrderSide::NoOrderSide => panic!("Invalid `OrderSide` for price improvement"),
+        }
+    }
+
+    /// Returns the `price` moved by `ticks` multiples of the `price_increment` in the adverse
+    /// direction for the given order `side` (up for buys, down for sells), as a conservative
+    /// (worst-case) fill price estimate.
+    ///
+    /// This is the mirror of [`Instrument::improve_price`], and the result is likewise clamped
+    /// to the `min_price` and `max_price` (when present).
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `side` is `NoOrderSide`.
+    fn apply_slippage(&self, price: Price, side: OrderSide, ticks: u32) -> Price {
+        let ticks = i128::from(ticks);
+        match side {
+            OrderSide::Buy => shift_price_by_ticks(self, price, ticks),
+            OrderSide::Sell => shift_price_by_ticks(self, price, -ticks),
+            OrderSide::NoOrderSide => panic!("Invalid `OrderSide` for slippage"),
+        }
+    }
+
+    /// Returns a ladder of tick-aligned prices from `from` to `to` (inclusive), stepping by
+    /// `step_ticks` multiples of the `price_increment`.
+    ///
+    /// The ladder ascends when `from <= to` and descends otherwise. A `from` price not aligned to
+    /// the `price_increment` is first rounded toward `to`, so every price lies within the bounds.
+    /// A zero `step_ticks` yields an empty ladder.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the ladder would exceed [`PRICE_LADDER_MAX_LEVELS`] levels.
+    fn price_ladder(&self, from: Price, to: Price, step_ticks: u32) -> anyhow::Result<Vec<Price>> {
+        let increment = i128::from(self.price_increment().raw);
+        let step = increment * i128::from(step_ticks);
+        if step <= 0 {
+            return Ok(Vec::new());
+        }
+
+        let (from_raw, to_raw) = (i128::from(from.raw), i128::from(to.raw));
+        let (start, direction) = if from_raw <= to_raw {
+            (
+                round_raw_to_increment(from_raw, increment, RoundingMode::Ceil),
+                1,
+            )
+        } else {
+            (
+                round_raw_to_increment(from_raw, increment, RoundingMode::Floor),
+                -1,
+            )
+        };
+        let span = (to_raw - start) * direction;
+        if span < 0 {
+            return Ok(Vec::new());
+        }
+
+        // Check the number of levels before allocating
+        let levels = span / step + 1;
+        if levels > PRICE_LADDER_MAX_LEVELS as i128 {
+            anyhow::bail!(
+                "Invalid price ladder: {levels} levels exceeded the maximum {max}",
+                max = PRICE_LADDER_MAX_LEVELS,
+            );
+        }
+        let precision = self.price_precision();
+        Ok((0..levels)
+            .map(|level| Price::from_raw((start + direction * level * step) as i64, precision))
+            .collect())
+    }
+
+    /// Returns the mid-price of the given `bid` and `ask`, rounded to the instrument's price
+    /// precision.
+    ///
+    /// The average is computed on the fixed-point raw values, with ties rounding
+    /// half-to-even. Use [`Instrument::mid_price_on_tick`] to snap the result to the
+    /// `price_increment`.
+    fn mid_price(&self, bid: Price, ask: Price) -> Price {
+        let precision = self.price_precision();
+        let unit = 10_i128.pow(u32::from(FIXED_PRECISION.saturating_sub(precision)));
+        let sum = i128::from(bid.raw) + i128::from(ask.raw);
+        // Round the doubled value to twice the unit to avoid losing the half before division
+        let raw = round_raw_to_increment(sum, unit * 2, RoundingMode::Nearest) / 2;
+        Price::from_raw(raw as i64, precision)
+    }
+
+    /// Returns the mid-price of the given `bid` and `ask`, rounded to the nearest multiple of
+    /// the instrument's `price_increment` (ties round to even).
+    fn mid_price_on_tick(&self, bid: Price, ask: Price) -> Price {
+        let sum = i128::from(bid.raw) + i128::from(ask.raw);
+        let increment = i128::from(self.price_increment().raw);
+        let raw = round_raw_to_increment(sum, increment * 2, RoundingMode::Nearest) / 2;
+        Price::from_raw(raw as i64, self.price_precision())
     }
 
-    /// Creates a new `Quantity` from the given `value` with the correct size precision for the instrument.
+    /// Returns the number of price increments (ticks) in the given `price`.
+    ///
+    /// Operates on the fixed-point raw values. A `price` not aligned to the `price_increment`
+    /// is rounded to the nearest tick, with ties rounding half-to-even.
+    fn price_to_ticks(&self, price: Price) -> i64 {
+        let increment = i128::from(self.price_increment().raw);
+        let raw = round_raw_to_increment(i128::from(price.raw), increment, RoundingMode::Nearest);
+        (raw / increment.max(1)) as i64
+    }
+
+    /// Returns the signed number of ticks from price `a` to price `b` (positive when `b > a`).
+    ///
+    /// Each price is first rounded to the nearest tick as per [`Instrument::price_to_ticks`].
+    fn ticks_between(&self, a: Price, b: Price) -> i64 {
+        self.price_to_ticks(b) - self.price_to_ticks(a)
+    }
+
+    /// Returns the number of `price_increment` ticks from the `min_price` to the `max_price`,
+    /// calculated with raw integer math as `(max_price - min_price) / price_increment`.
+    ///
+    /// A range which is not a whole number of ticks is rounded down. Returns `None` if either
+    /// price limit is absent, or if the limits are inverted.
+    fn tick_count(&self) -> Option<u64> {
+        let range = i128::from(self.max_price()?.raw) - i128::from(self.min_price()?.raw);
+        let increment = i128::from(self.price_increment().raw);
+        if increment <= 0 {
+            return None;
+        }
+        u64::try_from(range / increment).ok()
+    }
+
+    /// Creates a new `Quantity` from the given `value` rounded to the nearest multiple of the
+    /// instrument's `size_increment`, with the correct size precision for the instrument.
     fn make_qty(&self, value: f64) -> Quantity {
-        Quantity::new(value, self.size_precision())
+        let raw = round_raw_to_increment(
+            i128::from(f64_to_fixed_u64(value, FIXED_PRECISION)),
+            i128::from(self.size_increment().raw),
+            RoundingMode::Nearest,
+        );
+        Quantity::from_raw(raw as u64, self.size_precision())
+    }
+
+    /// Returns the given `qty` snapped to a multiple of the `size_increment` using the
+    /// rounding `mode`.
+    fn quantize_qty(&self, qty: Quantity, mode: RoundingMode) -> Quantity {
+        let raw = round_raw_to_increment(
+            i128::from(qty.raw),
+            i128::from(self.size_increment().raw),
+            mode,
+        );
+        Quantity::from_raw(raw as u64, self.size_precision())
+    }
+
+    /// Creates a new `Quantity` from the given `value` as per [`Instrument::make_qty`], then
+    /// clamped to the instrument's trading limits.
+    ///
+    /// The quantity is rounded down to a multiple of `lot_size` (when present), and a
+    /// quantity below `min_quantity` (when present) is raised to `min_quantity`.
+    fn make_qty_clamped(&self, value: f64) -> Quantity {
+        let mut qty = self.make_qty(value);
+        if let Some(lot_size) = self.lot_size() {
+            let raw = round_raw_to_increment(
+                i128::from(qty.raw),
+                i128::from(lot_size.raw),
+                RoundingMode::Floor,
+            );
+            qty = Quantity::from_raw(raw as u64, self.size_precision());
+        }
+        match self.min_quantity() {
+            Some(min_quantity) if qty < min_quantity => min_quantity,
+            _ => qty,
+        }
     }
 
     /// Calculates the notional value from the given parameters.
     /// The `use_quote_for_inverse` flag is only applicable for inverse instruments.
     ///
+    /// For linear instruments the notional is `quantity * multiplier * price` in the quote
+    /// currency; for inverse instruments it is `quantity * multiplier / price` in the base
+    /// currency (or `quantity` in the quote currency when `use_quote_for_inverse`).
+    ///
+    /// Intermediate math is performed with `Decimal`, rounding only once to the currency
+    /// precision.
+    ///
     /// # Panics
     ///
     /// This function panics:
     /// - If instrument is inverse and not `use_quote_for_inverse`, with no base currency.
+    /// - If `price` is zero and the instrument is inverse (division by zero).
+    /// - If the notional value is outside the representable range for `Money`.
     fn calculate_notional_value(
         &self,
         quantity: Quantity,
         price: Price,
         use_quote_for_inverse: Option<bool>,
     ) -> Money {
-        let use_quote_for_inverse = use_quote_for_inverse.unwrap_or(false);
-        let (amount, currency) = if self.is_inverse() {
-            if use_quote_for_inverse {
-                (quantity.as_f64(), self.quote_currency())
-            } else {
-                let amount =
-                    quantity.as_f64() * self.multiplier().as_f64() * (1.0 / price.as_f64());
-                let currency = self
-                    .base_currency()
-                    .expect("Error: no base currency for notional calculation");
-                (amount, currency)
+        let (amount, currency) = if self.is_inverse() && use_quote_for_inverse.unwrap_or(false) {
+            (quantity.as_decimal(), self.quote_currency())
+        } else {
+            notional_decimal(self, quantity, price)
+        };
+
+        // Only round to the currency precision once all intermediate math is complete
+        round_money(amount, currency)
+    }
+
+    /// Calculates the fee for a fill of the given `quantity` at `price` on the given
+    /// `liquidity_side`.
+    ///
+    /// The maker or taker fee rate is applied to the notional value, with the fee returned in
+    /// the settlement currency for inverse instruments and the quote currency otherwise. A
+    /// positive fee is a cost, while a negative fee (rebate) is a credit.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `liquidity_side` is `NoLiquiditySide`.
+    /// - If instrument is inverse with no base currency.
+    fn calculate_fee(
+        &self,
+        quantity: Quantity,
+        price: Price,
+        liquidity_side: LiquiditySide,
+    ) -> Money {
+        let fee_rate = match liquidity_side {
+            LiquiditySide::Maker => self.maker_fee(),
+            LiquiditySide::Taker => self.taker_fee(),
+            LiquiditySide::NoLiquiditySide => panic!("Invalid `LiquiditySide` for fee calculation"),
+        };
+        let (notional, _) = notional_decimal(self, quantity, price);
+        let currency = if self.is_inverse() {
+            self.settlement_currency()
+        } else {
+            self.quote_currency()
+        };
+        round_money(notional * fee_rate, currency)
+    }
+
+    /// Calculates the initial (order) margin requirement for the given `quantity` at `price`.
+    ///
+    /// The notional value (inverse aware) is multiplied by `margin_init`, with the margin
+    /// returned in the currency of the notional: the quote currency for linear instruments, and
+    /// the base currency for inverse instruments.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If instrument is inverse with no base currency.
+    fn calculate_margin_init(&self, quantity: Quantity, price: Price) -> Money {
+        let (notional, currency) = notional_decimal(self, quantity, price);
+        round_money(notional * self.margin_init(), currency)
+    }
+
+    /// Calculates the maintenance (position) margin requirement for the given `quantity` at
+    /// `price`.
+    ///
+    /// The notional value (inverse aware) is multiplied by `margin_maint`, with the margin
+    /// returned in the currency of the notional (as per [`Instrument::calculate_margin_init`]).
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If instrument is inverse with no base currency.
+    fn calculate_margin_maint(&self, quantity: Quantity, price: Price) -> Money {
+        let (notional, currency) = notional_decimal(self, quantity, price);
+        round_money(notional * self.margin_maint(), currency)
+    }
+
+    /// Calculates the change in initial margin requirement when resizing a position from
+    /// `old_quantity` to `new_quantity` at `price`.
+    ///
+    /// The result is signed: positive when more margin is required, and negative when margin is
+    /// released. Both requirements are calculated as per [`Instrument::calculate_margin_init`],
+    /// so the delta is in the currency of the notional.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If instrument is inverse with no base currency.
+    fn margin_delta(&self, old_quantity: Quantity, new_quantity: Quantity, price: Price) -> Money {
+        self.calculate_margin_init(new_quantity, price)
+            - self.calculate_margin_init(old_quantity, price)
+    }
+
+    /// Validates the given order `quantity` and `price` against the instrument's limits.
+    ///
+    /// Each present bound is checked in turn (quantity, price, then notional) and the first
+    /// violated constraint is returned. Notional limits are compared against
+    /// [`Instrument::calculate_notional_value`], computed in the currency of the limit: the
+    /// quote currency for linear instruments, and either the quote or base currency for
+    /// inverse instruments.
+    ///
+    /// A notional limit denominated in any other currency cannot be compared, and is reported
+    /// as [`InstrumentError::InvalidNotionalCurrency`] rather than as a false rejection.
+    fn validate_order(&self, quantity: Quantity, price: Price) -> Result<(), InstrumentError> {
+        if let Some(max_quantity) = self.max_quantity() {
+            if quantity > max_quantity {
+                return Err(InstrumentError::MaxQuantityExceeded(quantity, max_quantity));
+            }
+        }
+        if let Some(min_quantity) = self.min_quantity() {
+            if quantity < min_quantity {
+                return Err(InstrumentError::MinQuantityNotMet(quantity, min_quantity));
+            }
+        }
+        if let Some(max_price) = self.max_price() {
+            if price > max_price {
+                return Err(InstrumentError::MaxPriceExceeded(price, max_price));
+            }
+        }
+        if let Some(min_price) = self.min_price() {
+            if price < min_price {
+                return Err(InstrumentError::MinPriceNotMet(price, min_price));
+            }
+        }
+        let notional_in = |limit: Money| {
+            let use_quote_for_inverse = limit.currency == self.quote_currency();
+            let is_base = self.is_inverse() && self.base_currency() == Some(limit.currency);
+            if !use_quote_for_inverse && !is_base {
+                return Err(InstrumentError::InvalidNotionalCurrency(limit));
+            }
+            Ok(self.calculate_notional_value(quantity, price, Some(use_quote_for_inverse)))
+        };
+        if let Some(max_notional) = self.max_notional() {
+            let notional = notional_in(max_notional)?;
+            if notional > max_notional {
+                return Err(InstrumentError::MaxNotionalExceeded(notional, max_notional));
+            }
+        }
+        if let Some(min_notional) = self.min_notional() {
+            let notional = notional_in(min_notional)?;
+            if notional < min_notional {
+                return Err(InstrumentError::MinNotionalNotMet(notional, min_notional));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates that the given `price` is representable on the instrument's price grid.
+    ///
+    /// The `price` precision must not exceed the `price_precision`, and the `price` must be an
+    /// exact multiple of the `price_increment`.
+    fn validate_price_precision(&self, price: Price) -> Result<(), InstrumentError> {
+        if price.precision > self.price_precision() {
+            return Err(InstrumentError::PricePrecisionExceeded(
+                price,
+                self.price_precision(),
+            ));
+        }
+        let increment = self.price_increment();
+        if increment.raw > 0 && price.raw % increment.raw != 0 {
+            return Err(InstrumentError::PriceNotOnIncrement(price, increment));
+        }
+        Ok(())
+    }
+
+    /// Checks the given `price` against the instrument's absolute price limits, and against a
+    /// band of `tolerance` either side of the `reference` price.
+    ///
+    /// As with [`Instrument::price_at_pct`], the `tolerance` is a fraction rather than a
+    /// percentage (e.g. `0.05` for 5%).
+    ///
+    /// A price exactly on the edge of the band is accepted.
+    fn check_price_band(
+        &self,
+        price: Price,
+        reference: Price,
+        tolerance: Decimal,
+    ) -> Result<(), InstrumentError> {
+        if let Some(max_price) = self.max_price() {
+            if price > max_price {
+                return Err(InstrumentError::MaxPriceExceeded(price, max_price));
             }
+        }
+        if let Some(min_price) = self.min_price() {
+            if price < min_price {
+                return Err(InstrumentError::MinPriceNotMet(price, min_price));
+            }
+        }
+        let deviation = (price.as_decimal() - reference.as_decimal()).abs();
+        let max_deviation = reference.as_decimal().abs() * tolerance;
+        if deviation > max_deviation {
+            return Err(InstrumentError::PriceOutsideBand {
+                price,
+                reference,
+                tolerance,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a zero `Money` in the notional currency (at the currency precision), for
+    /// initializing PnL and fee accumulators.
+    ///
+    /// The notional currency is the quote currency for linear instruments and the base currency
+    /// for inverse instruments, matching the currency of [`Instrument::calculate_pnl`] and the
+    /// fee and margin calculations (which may differ from the settlement currency).
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the instrument is inverse and has no base currency.
+    fn zero_notional(&self) -> Money {
+        Money::new(0.0, notional_currency(self))
+    }
+
+    /// Calculates the PnL for a position of `quantity` on the given `side`, opened at `entry`
+    /// and closed at `exit`.
+    ///
+    /// For linear instruments the PnL is `(exit - entry) * quantity * multiplier` in the quote
+    /// currency; for inverse instruments it is `quantity * multiplier * (1 / entry - 1 / exit)`
+    /// in the base currency. The sign is flipped for a short position, and a `Flat` (or no)
+    /// position side has zero PnL. Intermediate math is performed with `Decimal`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If instrument is inverse with no base currency.
+    /// - If instrument is inverse and `entry` or `exit` is zero.
+    fn calculate_pnl(
+        &self,
+        entry: Price,
+        exit: Price,
+        quantity: Quantity,
+        side: PositionSide,
+    ) -> Money {
+        let sign = match side {
+            PositionSide::Long => Decimal::ONE,
+            PositionSide::Short => Decimal::NEGATIVE_ONE,
+            PositionSide::Flat | PositionSide::NoPositionSide => Decimal::ZERO,
+        };
+        let size = quantity.as_decimal() * self.multiplier().as_decimal();
+        let (pnl, currency) = if self.is_inverse() {
+            let pnl = size * (Decimal::ONE / entry.as_decimal() - Decimal::ONE / exit.as_decimal());
+            let currency = self
+                .base_currency()
+                .expect("Error: no base currency for PnL calculation");
+            (pnl, currency)
+        } else {
+            let pnl = (exit.as_decimal() - entry.as_decimal()) * size;
+            (pnl, self.quote_currency())
+        };
+
+        round_money(pnl * sign, currency)
+    }
+
+    /// Returns the exit price at which a position on the given `side`, opened at `entry`, breaks
+    /// even after round-trip fees.
+    ///
+    /// The entry fill is assumed to pay the `maker_fee` and the exit fill the `taker_fee`. Fees
+    /// are charged on the notional value of each fill, so for linear instruments (fees in the
+    /// quote currency) a long breaks even at `entry * (1 + maker_fee) / (1 - taker_fee)`. For
+    /// inverse instruments the notional (and so the fees) are in the base currency, giving
+    /// `entry * (1 + taker_fee) / (1 - maker_fee)` for a long. The short side mirrors these.
+    ///
+    /// As fees are proportional to the notional, the result is independent of the position size.
+    /// The price is rounded away from `entry` to the price grid (up for a long, down for a
+    /// short), so closing at the returned price never realizes a net loss. A `Flat` (or no)
+    /// position side returns `entry` unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the resulting price is outside the representable range for `Price`.
+    fn break_even_price(&self, entry: Price, side: PositionSide) -> Price {
+        let (entry_fee, exit_fee) = (self.maker_fee(), self.taker_fee());
+        let (ratio, mode) = match (side, self.is_inverse()) {
+            (PositionSide::Long, false) => (
+                (Decimal::ONE + entry_fee) / (Decimal::ONE - exit_fee),
+                RoundingMode::Ceil,
+            ),
+            (PositionSide::Long, true) => (
+                (Decimal::ONE + exit_fee) / (Decimal::ONE - entry_fee),
+                RoundingMode::Ceil,
+            ),
+            (PositionSide::Short, false) => (
+                (Decimal::ONE - entry_fee) / (Decimal::ONE + exit_fee),
+                RoundingMode::Floor,
+            ),
+            (PositionSide::Short, true) => (
+                (Decimal::ONE - exit_fee) / (Decimal::ONE + entry_fee),
+                RoundingMode::Floor,
+            ),
+            (PositionSide::Flat | PositionSide::NoPositionSide, _) => return entry,
+        };
+        let raw = round_raw_to_increment(
+            decimal_to_raw(entry.as_decimal() * ratio),
+            i128::from(self.price_increment().raw),
+            mode,
+        );
+        let raw = i64::try_from(raw).expect("Invalid raw value for price");
+        Price::from_raw(raw, self.price_precision())
+    }
+
+    /// Calculates the PnL for a partially closed position on the given `side` opened at `entry`,
+    /// split into `(realized, unrealized)` components at the `current` price.
+    ///
+    /// The realized PnL is for the `closed_qty` (closed at `current`), and the unrealized PnL is
+    /// for the remaining `open_qty` (marked at `current`). Each is calculated as per
+    /// [`Instrument::calculate_pnl`], so both are in the same currency and use the inverse
+    /// formula where applicable.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If instrument is inverse with no base currency.
+    /// - If instrument is inverse and `entry` or `current` is zero.
+    fn calculate_pnl_split(
+        &self,
+        entry: Price,
+        current: Price,
+        closed_qty: Quantity,
+        open_qty: Quantity,
+        side: PositionSide,
+    ) -> (Money, Money) {
+        let realized = self.calculate_pnl(entry, current, closed_qty, side);
+        let unrealized = self.calculate_pnl(entry, current, open_qty, side);
+        (realized, unrealized)
+    }
+
+    /// Returns an estimate of the liquidation price for an isolated position of `quantity` on
+    /// the given `side`, opened at `entry` with the `wallet_balance` as collateral.
+    ///
+    /// The position is taken to be liquidated when its equity (`wallet_balance` plus unrealized
+    /// PnL) falls to the maintenance margin (`margin_maint` of the notional value). With
+    /// `size = quantity * multiplier` and `m = margin_maint`, for linear instruments this gives
+    /// `(entry * size - wallet_balance) / (size * (1 - m))` for a long and
+    /// `(entry * size + wallet_balance) / (size * (1 + m))` for a short. For inverse instruments
+    /// the notional is `size / price` in the base currency, giving
+    /// `size * (1 + m) / (wallet_balance + size / entry)` for a long and
+    /// `size * (1 - m) / (size / entry - wallet_balance)` for a short.
+    ///
+    /// This is an estimate only: it ignores fees, funding, cross-margin collateral and any
+    /// venue specific liquidation rules. The price is rounded towards `entry` to the price
+    /// grid (up for a long, down for a short), so the estimate errs on the side of caution.
+    ///
+    /// Returns `None` if the inputs are degenerate: a zero `quantity` or `entry`, a `Flat` (or
+    /// no) position side, a `wallet_balance` not in the settlement currency, or where no
+    /// positive liquidation price exists (e.g. a long fully collateralized by the balance).
+    fn liquidation_price(
+        &self,
+        entry: Price,
+        quantity: Quantity,
+        side: PositionSide,
+        wallet_balance: Money,
+    ) -> Option<Price> {
+        if quantity.raw == 0
+            || entry.raw <= 0
+            || wallet_balance.currency != self.settlement_currency()
+        {
+            return None;
+        }
+
+        let size = quantity.as_decimal() * self.multiplier().as_decimal();
+        let margin = self.margin_maint();
+        let balance = wallet_balance.as_decimal();
+        let entry_value = entry.as_decimal();
+        let (numerator, denominator, mode) = match (side, self.is_inverse()) {
+            (PositionSide::Long, false) => (
+                entry_value * size - balance,
+                size * (Decimal::ONE - margin),
+                RoundingMode::Ceil,
+            ),
+            (PositionSide::Short, false) => (
+                entry_value * size + balance,
+                size * (Decimal::ONE + margin),
+                RoundingMode::Floor,
+            ),
+            (PositionSide::Long, true) => (
+                size * (Decimal::ONE + margin),
+                balance + size / entry_value,
+                RoundingMode::Ceil,
+            ),
+            (PositionSide::Short, true) => (
+                size * (Decimal::ONE - margin),
+                size / entry_value - balance,
+                RoundingMode::Floor,
+            ),
+            (PositionSide::Flat | PositionSide::NoPositionSide, _) => return None,
+        };
+        if numerator <= Decimal::ZERO || denominator <= Decimal::ZERO {
+            return None;
+        }
+        let price = numerator.checked_div(denominator)?;
+        if price > Decimal::from_f64(PRICE_MAX)? {
+            return None;
+        }
+
+        let raw = round_raw_to_increment(
+            decimal_to_raw(price),
+            i128::from(self.price_increment().raw),
+            mode,
+        );
+        let raw = i64::try_from(raw).ok().filter(|raw| *raw > 0)?;
+        Some(Price::from_raw(raw, self.price_precision()))
+    }
+
+    /// Returns the value of a one tick (`price_increment`) price move for a single unit of
+    /// quantity at the given `price`.
+    ///
+    /// For linear instruments the tick value is `price_increment * multiplier` in the quote
+    /// currency, independent of `price`. For inverse instruments the exact PnL of a one tick
+    /// move is `multiplier * (1 / price - 1 / (price + price_increment))`; this is approximated
+    /// to first order as `multiplier * price_increment / price^2` in the base currency, which
+    /// may round to zero at the currency precision for high prices.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If instrument is inverse with no base currency.
+    /// - If instrument is inverse and `price` is zero.
+    fn tick_value(&self, price: Price) -> Money {
+        let tick = self.price_increment().as_decimal() * self.multiplier().as_decimal();
+        if self.is_inverse() {
+            let price = price.as_decimal();
+            let currency = self
+                .base_currency()
+                .expect("Error: no base currency for tick value calculation");
+            round_money(tick / (price * price), currency)
+        } else {
+            round_money(tick, self.quote_currency())
+        }
+    }
+
+    /// Returns the quantity (number of contracts) for the given target `notional` at `price`.
+    ///
+    /// For linear instruments the quantity is `notional / price / multiplier`, with the
+    /// `notional` in the quote currency; for inverse instruments it is
+    /// `notional * price / multiplier`, with the `notional` in the settlement currency. The
+    /// quantity is capped at the `max_quantity` (when present) and the maximum representable
+    /// quantity, rounded to the nearest multiple of the `size_increment`, then clamped to the
+    /// `min_quantity` and `max_quantity` (when present).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `notional` currency does not match the expected currency.
+    /// - If the `notional` is negative.
+    /// - If `price` is not positive.
+    fn notional_to_quantity(&self, notional: Money, price: Price) -> anyhow::Result<Quantity> {
+        let expected_currency = if self.is_inverse() {
+            self.settlement_currency()
         } else {
-            let amount = quantity.as_f64() * self.multiplier().as_f64() * price.as_f64();
-            let currency = self.quote_currency();
-            (amount, currency)
+            self.quote_currency()
         };
+        if notional.currency != expected_currency {
+            anyhow::bail!(
+                "Invalid notional currency {}, expected {expected_currency}",
+                notional.currency,
+            );
+        }
+        check_non_negative_f64(notional.as_f64(), stringify!(notional))?;
+        check_positive_i64(price.raw, stringify!(price))?;
+
+        let multiplier = self.multiplier().as_decimal();
+        let value = if self.is_inverse() {
+            notional.as_decimal() * price.as_decimal() / multiplier
+        } else {
+            notional.as_decimal() / price.as_decimal() / multiplier
+        };
+
+        // Cap in `Decimal` before building the quantity
+        let cap = self
+            .max_quantity()
+            .map_or(quantity_max_decimal(), |max_quantity| {
+                max_quantity.as_decimal().min(quantity_max_decimal())
+            });
+        let mut quantity = decimal_to_quantity(self, value.min(cap), RoundingMode::Nearest);
+
+        if let Some(max_quantity) = self.max_quantity() {
+            quantity = quantity.min(max_quantity);
+        }
+        if let Some(min_quantity) = self.min_quantity() {
+            quantity = quantity.max(min_quantity);
+        }
+        Ok(quantity)
+    }
+
+    /// Returns the maximum quantity (number of contracts) which can be opened at `price` with
+    /// the given initial `margin_budget`.
+    ///
+    /// This inverts [`Instrument::calculate_margin_init`]: for linear instruments the quantity
+    /// is `margin_budget / (price * multiplier * margin_init)`, and for inverse instruments it is
+    /// `margin_budget * price / (multiplier * margin_init)`. The quantity is capped at the
+    /// `max_quantity` (when present) and the maximum representable quantity, then rounded down
+    /// to a multiple of the `size_increment` so the budget is never exceeded.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `margin_budget` currency does not match the margin currency (the quote currency
+    ///   for linear instruments, and the base currency for inverse instruments).
+    /// - If the `margin_budget` is negative.
+    /// - If `price` is not positive.
+    /// - If `margin_init` is not positive.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If instrument is inverse with no base currency.
+    fn max_qty_for_margin(&self, margin_budget: Money, price: Price) -> anyhow::Result<Quantity> {
+        let margin_currency = if self.is_inverse() {
+            self.base_currency()
+                .expect("Error: no base currency for inverse instrument")
+        } else {
+            self.quote_currency()
+        };
+        if margin_budget.currency != margin_currency {
+            anyhow::bail!(
+                "Invalid margin budget currency {}, expected {margin_currency}",
+                margin_budget.currency,
+            );
+        }
+        check_non_negative_f64(margin_budget.as_f64(), stringify!(margin_budget))?;
+        check_positive_i64(price.raw, stringify!(price))?;
+        let margin_init = self.margin_init();
+        if margin_init <= Decimal::ZERO {
+            anyhow::bail!("Invalid `margin_init` {margin_init}, was not positive");
+        }
+
+        let margin_per_unit = self.multiplier().as_decimal() * margin_init;
+        let budget = margin_budget.as_decimal();
+        let value = if self.is_inverse() {
+            budget
+                .checked_mul(price.as_decimal())
+                .and_then(|value| value.checked_div(margin_per_unit))
+        } else {
+            price
+                .as_decimal()
+                .checked_mul(margin_per_unit)
+                .and_then(|divisor| budget.checked_div(divisor))
+        };
+
+        // Cap in `Decimal` before building the quantity, where an overflow is beyond any cap
+        let cap = self
+            .max_quantity()
+            .map_or(quantity_max_decimal(), |max_quantity| {
+                max_quantity.as_decimal().min(quantity_max_decimal())
+            });
+        let value = value.map_or(cap, |value| value.min(cap));
+        Ok(decimal_to_quantity(self, value, RoundingMode::Floor))
+    }
+
+    /// Returns the smallest valid order quantity at `price`.
+    ///
+    /// This is the larger of the `min_quantity` and the quantity required to meet the
+    /// `min_notional` at `price` (as per [`Instrument::validate_order`]), rounded up to a
+    /// multiple of the `size_increment`. When neither limit is present, or both are smaller,
+    /// the `size_increment` is returned.
+    ///
+    /// A `min_notional` denominated in a currency which cannot be compared with the order
+    /// notional is ignored.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `price` is zero and a comparable `min_notional` is present.
+    fn min_order_quantity(&self, price: Price) -> Quantity {
+        let mut value = self.size_increment().as_decimal();
 
-        Money::new(amount, currency)
+        if let Some(min_quantity) = self.min_quantity() {
+            value = value.max(min_quantity.as_decimal());
+        }
+        if let Some(required) = self
+            .min_notional()
+            .and_then(|limit| notional_limit_to_quantity(self, limit, price))
+        {
+            value = value.max(required);
+        }
+
+        decimal_to_quantity(self, value, RoundingMode::Ceil)
+    }
+
+    /// Truncates `quantity` to the largest quantity within the `max_quantity` and the
+    /// `max_notional` at `price` (as per [`Instrument::validate_order`]).
+    ///
+    /// When a limit is exceeded the result is rounded down to a multiple of the
+    /// `size_increment`, so it never exceeds either limit. A `quantity` already within the
+    /// limits is returned unchanged.
+    ///
+    /// A `max_notional` denominated in a currency which cannot be compared with the order
+    /// notional is ignored.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `price` is zero and a comparable `max_notional` is present.
+    fn truncate_to_limits(&self, quantity: Quantity, price: Price) -> Quantity {
+        let mut value = quantity.as_decimal();
+        if let Some(max_quantity) = self.max_quantity() {
+            value = value.min(max_quantity.as_decimal());
+        }
+        if let Some(allowed) = self
+            .max_notional()
+            .and_then(|limit| notional_limit_to_quantity(self, limit, price))
+        {
+            value = value.min(allowed);
+        }
+        if value >= quantity.as_decimal() {
+            return quantity;
+        }
+        decimal_to_quantity(self, value, RoundingMode::Floor)
     }
 
     /// Returns the equivalent quantity of the base asset.
+    ///
+    /// The quote denominated `quantity` is divided by `last_px` and rounded to the nearest
+    /// multiple of the instrument's `size_increment` (at the instrument's size precision),
+    /// saturating at the maximum representable quantity.
     fn calculate_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
-        let value = quantity.as_f64() * (1.0 / last_px.as_f64());
-        Quantity::new(value, self.size_precision())
+        let value = quantity.as_decimal() / last_px.as_decimal();
+        decimal_to_quantity(self, value, RoundingMode::Nearest)
+    }
+}
+
+/// Moves `price` by a signed number of `ticks` of the instrument's `price_increment`, clamped to
+/// the instrument's `min_price` and `max_price` (when present).
+fn shift_price_by_ticks<I: Instrument + ?Sized>(
+    instrument: &I,
+    price: Price,
+    ticks: i128,
+) -> Price {
+    let mut raw = i128::from(price.raw) + ticks * i128::from(instrument.price_increment().raw);
+    if let Some(min_price) = instrument.min_price() {
+        raw = raw.max(i128::from(min_price.raw));
     }
+    if let Some(max_price) = instrument.max_price() {
+        raw = raw.min(i128::from(max_price.raw));
+    }
+    let raw = raw.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64;
+    Price::from_raw(raw, instrument.price_precision())
+}
+
+/// Converts the decimal `value` to a raw fixed-point value, rounding to the nearest raw unit.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the scaled `value` is outside the range of `Decimal`.
+fn decimal_to_raw(value: Decimal) -> i128 {
+    checked_decimal_to_raw(value).expect("Invalid decimal for raw value")
 }
 
+/// Converts the decimal `value` to a raw fixed-point value, rounding to the nearest raw unit,
+/// or `None` if the scaled `value` is outside the range of `Decimal`.
+fn checked_decimal_to_raw(value: Decimal) -> Option<i128> {
+    let scale = Decimal::from(10_i64.pow(u32::from(FIXED_PRECISION)));
+    value.checked_mul(scale)?.round().to_i128()
+}
+
+/// Returns `QUANTITY_MAX` as a `Decimal`.
+fn quantity_max_decimal() -> Decimal {
+    Decimal::from(QUANTITY_MAX as u64)
+}
+
+/// Converts the decimal `value` to a `Quantity` at the instrument's size precision, rounded to a
+/// multiple of the `size_increment` with the rounding `mode`.
+///
+/// The conversion is performed on raw fixed-point values (without an `f64` round trip), and
+/// saturates at zero and the largest multiple of the `size_increment` within `QUANTITY_MAX`.
+fn decimal_to_quantity<T: Instrument + ?Sized>(
+    instrument: &T,
+    value: Decimal,
+    mode: RoundingMode,
+) -> Quantity {
+    let increment = i128::from(instrument.size_increment().raw);
+    let max_raw = round_raw_to_increment(
+        decimal_to_raw(quantity_max_decimal()),
+        increment,
+        RoundingMode::Floor,
+    );
+    let raw = checked_decimal_to_raw(value.min(quantity_max_decimal())).unwrap_or(max_raw);
+    let raw = round_raw_to_increment(raw, increment, mode).clamp(0, max_raw);
+    Quantity::from_raw(raw as u64, instrument.size_precision())
+}
+
+/// Returns the unrounded notional value of `quantity` at `price` and its currency, as per
+/// [`Instrument::calculate_notional_value`] (in the base currency for inverse instruments).
+///
+/// Callers applying a rate to the notional (such as fees and margins) round the final amount
+/// once, rather than rounding the notional first.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If instrument is inverse with no base currency.
+/// - If `price` is zero and the instrument is inverse (division by zero).
+fn notional_decimal<T: Instrument + ?Sized>(
+    instrument: &T,
+    quantity: Quantity,
+    price: Price,
+) -> (Decimal, Currency) {
+    let size = quantity.as_decimal() * instrument.multiplier().as_decimal();
+    let currency = notional_currency(instrument);
+    if instrument.is_inverse() {
+        (size / price.as_decimal(), currency)
+    } else {
+        (size * price.as_decimal(), currency)
+    }
+}
+
+/// Returns the currency of the notional, the base currency for inverse instruments and the quote
+/// currency for linear instruments.
+fn notional_currency<T: Instrument + ?Sized>(instrument: &T) -> Currency {
+    if instrument.is_inverse() {
+        instrument
+            .base_currency()
+            .expect("Error: no base currency for notional calculation")
+    } else {
+        instrument.quote_currency()
+    }
+}
+
+/// Returns the quantity at `price` whose notional equals the notional `limit`, calculated as
+/// per [`Instrument::validate_order`], or `None` if the `limit` currency cannot be compared.
+///
+/// The `limit` is compared in the quote currency for linear instruments, and either the quote
+/// or base currency for inverse instruments.
+fn notional_limit_to_quantity<T: Instrument + ?Sized>(
+    instrument: &T,
+    limit: Money,
+    price: Price,
+) -> Option<Decimal> {
+    let multiplier = instrument.multiplier().as_decimal();
+    let notional = limit.as_decimal();
+    if limit.currency == instrument.quote_currency() {
+        if instrument.is_inverse() {
+            Some(notional)
+        } else {
+            Some(notional / (price.as_decimal() * multiplier))
+        }
+    } else if instrument.is_inverse() && instrument.base_currency() == Some(limit.currency) {
+        Some(notional * price.as_decimal() / multiplier)
+    } else {
+        None
+    }
+}
+
+/// The maximum number of levels returned by [`Instrument::price_ladder`].
+pub const PRICE_LADDER_MAX_LEVELS: usize = 100_000;
+
 pub const EXPIRING_INSTRUMENT_TYPES: [InstrumentClass; 4] = [
     InstrumentClass::Future,
     InstrumentClass::FutureSpread,
@@ -122,6 +122,8 @@ pub fn crypto_perpetual_ethusdt() -> CryptoPerpetual {
         Some(dec!(0.35)),
         Some(dec!(0.0002)),
         Some(dec!(0.0004)),
+        None,
+        None,
         UnixNanos::default(),
         UnixNanos::default(),
     )
@@ -152,6 +154,8 @@ pub fn xbtusd_bitmex() -> CryptoPerpetual {
         Some(dec!(0.0035)),
         Some(dec!(-0.00025)),
         Some(dec!(0.00075)),
+        None,
+        None,
         UnixNanos::default(),
         UnixNanos::default(),
     )
@@ -182,6 +186,8 @@ pub fn ethusdt_bitmex() -> CryptoPerpetual {
         Some(dec!(0.0035)),
         Some(dec!(-0.00025)),
         Some(dec!(0.00075)),
+        None,
+        None,
         UnixNanos::default(),
         UnixNanos::default(),
     )
@@ -18,7 +18,10 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use nautilus_core::python::{serialization::from_dict_pyo3, to_pyvalue_err};
+use nautilus_core::{
+    nanos::UnixNanos,
+    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+};
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use rust_decimal::Decimal;
 
@@ -32,7 +35,7 @@ use crate::{
 impl CryptoPerpetual {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (id, raw_symbol, base_currency, quote_currency, settlement_currency, is_inverse, price_precision, size_precision, price_increment, size_increment, ts_event, ts_init, multiplier=None, lot_size=None, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None, margin_init=None, margin_maint=None, maker_fee=None, taker_fee=None))]
+    #[pyo3(signature = (id, raw_symbol, base_currency, quote_currency, settlement_currency, is_inverse, price_precision, size_precision, price_increment, size_increment, ts_event, ts_init, multiplier=None, lot_size=None, max_quantity=None, min_quantity=None, max_notional=None, min_notional=None, max_price=None, min_price=None, margin_init=None, margin_maint=None, maker_fee=None, taker_fee=None, funding_rate=None, funding_interval_ns=None))]
     fn py_new(
         id: InstrumentId,
         raw_symbol: Symbol,
@@ -58,6 +61,8 @@ impl CryptoPerpetual {
         margin_maint: Option<Decimal>,
         maker_fee: Option<Decimal>,
         taker_fee: Option<Decimal>,
+        funding_rate: Option<Decimal>,
+        funding_interval_ns: Option<u64>,
     ) -> PyResult<Self> {
         Self::new_checked(
             id,
@@ -82,6 +87,8 @@ impl CryptoPerpetual {
             margin_maint,
             maker_fee,
             taker_fee,
+            funding_rate,
+            funding_interval_ns.map(UnixNanos::from),
             ts_event.into(),
             ts_init.into(),
         )
@@ -101,6 +108,10 @@ impl CryptoPerpetual {
         hasher.finish() as isize
     }
 
+    fn __repr__(&self) -> String {
+        format!("{}(id={})", stringify!(CryptoPerpetual), self.id)
+    }
+
     #[getter]
     fn type_str(&self) -> &str {
         stringify!(CryptoPerpetual)
@@ -250,6 +261,18 @@ impl CryptoPerpetual {
         self.taker_fee
     }
 
+    #[getter]
+    #[pyo3(name = "funding_rate")]
+    fn py_funding_rate(&self) -> Option<Decimal> {
+        self.funding_rate
+    }
+
+    #[getter]
+    #[pyo3(name = "funding_interval_ns")]
+    fn py_funding_interval_ns(&self) -> Option<u64> {
+        self.funding_interval_ns.map(|interval| interval.as_u64())
+    }
+
     #[getter]
     #[pyo3(name = "info")]
     fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
@@ -312,6 +335,145 @@ impl CryptoPerpetual {
             Some(value) => dict.set_item("min_price", value.to_string())?,
             None => dict.set_item("min_price", py.None())?,
         }
+        match self.funding_rate {
+            Some(value) => dict.set_item("funding_rate", value.to_string())?,
+            None => dict.set_item("funding_rate", py.None())?,
+        }
+        match self.funding_interval_ns {
+            Some(value) => dict.set_item("funding_interval_ns", value.as_u64())?,
+            None => dict.set_item("funding_interval_ns", py.None())?,
+        }
         Ok(dict.into())
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use pyo3::{prelude::*, prepare_freethreaded_python, types::PyDict};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        identifiers::{InstrumentId, Symbol},
+        instruments::{stubs::*, CryptoPerpetual},
+        types::{Currency, Price, Quantity},
+    };
+
+    #[rstest]
+    fn test_py_new() {
+        let instrument = CryptoPerpetual::py_new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Symbol::from("ETHUSDT"),
+            Currency::from("ETH"),
+            Currency::from("USDT"),
+            Currency::from("USDT"),
+            false,
+            2,
+            3,
+            Price::from("0.01"),
+            Quantity::from("0.001"),
+            0,
+            0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(dec!(0.0002)),
+            Some(dec!(0.0004)),
+            Some(dec!(0.0001)),
+            Some(28_800_000_000_000),
+        )
+        .unwrap();
+
+        assert_eq!(instrument.py_price_increment(), Price::from("0.01"));
+        assert_eq!(instrument.py_maker_fee(), dec!(0.0002));
+        assert_eq!(instrument.py_funding_rate(), Some(dec!(0.0001)));
+        assert_eq!(
+            instrument.funding_interval_ns,
+            Some(UnixNanos::from(28_800_000_000_000))
+        );
+        assert_eq!(
+            instrument.py_funding_interval_ns(),
+            Some(28_800_000_000_000)
+        );
+    }
+
+    #[rstest]
+    fn test_py_new_invalid() {
+        let result = CryptoPerpetual::py_new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Symbol::from("ETHUSDT"),
+            Currency::from("ETH"),
+            Currency::from("USDT"),
+            Currency::from("USDT"),
+            false,
+            3, // Mismatched with price increment precision
+            3,
+            Price::from("0.01"),
+            Quantity::from("0.001"),
+            0,
+            0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_dict_round_trip(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let crypto_perpetual = crypto_perpetual_ethusdt;
+            let values = crypto_perpetual.py_to_dict(py).unwrap();
+            let values: Py<PyDict> = values.extract(py).unwrap();
+            let new_crypto_perpetual = CryptoPerpetual::py_from_dict(py, values).unwrap();
+            assert_eq!(crypto_perpetual, new_crypto_perpetual);
+            assert!(crypto_perpetual.equals_full(&new_crypto_perpetual));
+        })
+    }
+
+    #[rstest]
+    fn test_dict_round_trip_with_funding(xbtusd_bitmex: CryptoPerpetual) {
+        prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let mut crypto_perpetual = xbtusd_bitmex;
+            crypto_perpetual.funding_rate = Some(dec!(0.0001));
+            crypto_perpetual.funding_interval_ns = Some(UnixNanos::from(28_800_000_000_000));
+            let values = crypto_perpetual.py_to_dict(py).unwrap();
+            let values: Py<PyDict> = values.extract(py).unwrap();
+            let new_crypto_perpetual = CryptoPerpetual::py_from_dict(py, values).unwrap();
+            assert!(crypto_perpetual.equals_full(&new_crypto_perpetual));
+        })
+    }
+
+    #[rstest]
+    fn test_repr(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        assert_eq!(
+            crypto_perpetual_ethusdt.__repr__(),
+            "CryptoPerpetual(id=ETHUSDT-PERP.BINANCE)"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Represents a rate quoted in basis points (one hundredth of one percent).
+
+use std::fmt::{Display, Formatter};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// The number of basis points in a rate of one (100%).
+const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Represents a rate quoted in basis points, such as an exchange fee schedule.
+///
+/// One basis point is `0.0001` as a decimal rate, so `BasisPoints::new(dec!(10))` converts to a
+/// rate of `0.001` (0.1%).
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub struct BasisPoints(Decimal);
+
+impl BasisPoints {
+    /// Creates a new [`BasisPoints`] instance from the given number of `bps`.
+    #[must_use]
+    pub const fn new(bps: Decimal) -> Self {
+        Self(bps)
+    }
+
+    /// Creates a new [`BasisPoints`] instance from the given decimal `rate` (e.g. `0.001`).
+    #[must_use]
+    pub fn from_rate(rate: Decimal) -> Self {
+        Self(rate * BPS_PER_UNIT)
+    }
+
+    /// Returns the number of basis points.
+    #[must_use]
+    pub const fn bps(&self) -> Decimal {
+        self.0
+    }
+
+    /// Returns the value as a decimal rate (e.g. `10` bps is `0.001`).
+    #[must_use]
+    pub fn to_decimal(&self) -> Decimal {
+        (self.0 / BPS_PER_UNIT).normalize()
+    }
+}
+
+impl Display for BasisPoints {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} bps", self.0)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[rstest]
+    #[case(dec!(10), dec!(0.001))]
+    #[case(dec!(1), dec!(0.0001))]
+    #[case(dec!(2.5), dec!(0.00025))]
+    #[case(dec!(-2.5), dec!(-0.00025))]
+    #[case(dec!(0), dec!(0))]
+    fn test_to_decimal(#[case] bps: Decimal, #[case] expected: Decimal) {
+        assert_eq!(BasisPoints::new(bps).to_decimal(), expected);
+    }
+
+    #[rstest]
+    fn test_from_rate() {
+        let bps = BasisPoints::from_rate(dec!(0.001));
+        assert_eq!(bps.bps(), dec!(10));
+        assert_eq!(bps, BasisPoints::new(dec!(10)));
+    }
+
+    #[rstest]
+    fn test_display() {
+        assert_eq!(BasisPoints::new(dec!(10)).to_string(), "10 bps");
+    }
+}
@@ -18,6 +18,7 @@
 //! Handles up to 9 decimals of precision.
 
 use std::{
+    collections::HashMap,
     fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
     str::FromStr,
@@ -170,6 +171,43 @@ impl Currency {
     }
 }
 
+/// Resolves currency codes to [`Currency`] values, such as when importing instrument definitions.
+///
+/// Currencies added to the resolver take precedence, with any other code looked up in the global
+/// currency registry (which includes the built-in currencies).
+#[derive(Clone, Debug, Default)]
+pub struct CurrencyResolver {
+    currencies: HashMap<Ustr, Currency>,
+}
+
+impl CurrencyResolver {
+    /// Creates a new [`CurrencyResolver`] instance which resolves from the global registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `currency` to the resolver, replacing any currency with the same code.
+    pub fn add(&mut self, currency: Currency) -> &mut Self {
+        self.currencies.insert(currency.code, currency);
+        self
+    }
+
+    /// Resolves the given currency `code`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `code` was neither added to the resolver nor found in the global registry.
+    pub fn resolve(&self, code: &str) -> anyhow::Result<Currency> {
+        let added = Ustr::from_existing(code).and_then(|code| self.currencies.get(&code));
+        match added {
+            Some(currency) => Ok(*currency),
+            None => Currency::from_str(code),
+        }
+    }
+}
+
 impl PartialEq for Currency {
     fn eq(&self, other: &Self) -> bool {
         self.code == other.code
@@ -249,7 +287,10 @@ impl<'de> Deserialize<'de> for Currency {
 mod tests {
     use rstest::rstest;
 
-    use crate::{enums::CurrencyType, types::Currency};
+    use crate::{
+        enums::CurrencyType,
+        types::{currency::CurrencyResolver, Currency},
+    };
 
     #[rstest]
     fn test_debug() {
@@ -319,6 +360,28 @@ mod tests {
         assert!(invalid_currency.is_none());
     }
 
+    #[rstest]
+    fn test_currency_resolver_global_registry() {
+        let resolver = CurrencyResolver::new();
+        assert_eq!(resolver.resolve("USDT").unwrap(), Currency::USDT());
+        assert_eq!(
+            resolver.resolve("NOT_A_CODE").unwrap_err().to_string(),
+            "Unknown currency: NOT_A_CODE"
+        );
+    }
+
+    #[rstest]
+    fn test_currency_resolver_added_currency_takes_precedence() {
+        let venue_usdt = Currency::new("USDT", 6, 0, "Tether", CurrencyType::Crypto);
+        let local = Currency::new("LOCALCOIN", 4, 0, "Local coin", CurrencyType::Crypto);
+        let mut resolver = CurrencyResolver::new();
+        resolver.add(venue_usdt).add(local);
+
+        assert_eq!(resolver.resolve("USDT").unwrap().precision, 6);
+        assert_eq!(resolver.resolve("LOCALCOIN").unwrap(), local);
+        assert_eq!(Currency::try_from_str("LOCALCOIN"), None); // Not registered globally
+    }
+
     #[rstest]
     fn test_equality() {
         let currency1 = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
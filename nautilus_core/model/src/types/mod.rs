@@ -16,18 +16,22 @@
 //! Value types for the trading domain model such as `Price`, `Quantity` and `Money`.
 
 pub mod balance;
+pub mod basis_points;
 pub mod currency;
 pub mod fixed;
 pub mod money;
 pub mod price;
 pub mod quantity;
+pub mod rounding;
 
 #[cfg(feature = "stubs")]
 pub mod stubs;
 
 // Re-exports
 pub use balance::{AccountBalance, MarginBalance};
-pub use currency::Currency;
-pub use money::{Money, MONEY_MAX, MONEY_MIN};
+pub use basis_points::BasisPoints;
+pub use currency::{Currency, CurrencyResolver};
+pub use money::{round_money, Money, MONEY_MAX, MONEY_MIN};
 pub use price::{Price, ERROR_PRICE, PRICE_ERROR, PRICE_MAX, PRICE_MIN, PRICE_UNDEF};
 pub use quantity::{Quantity, QUANTITY_MAX, QUANTITY_MIN, QUANTITY_UNDEF};
+pub use rounding::RoundToIncrement;
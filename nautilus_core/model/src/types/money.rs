@@ -24,7 +24,7 @@ use std::{
 };
 
 use nautilus_core::correctness::{check_in_range_inclusive_f64, FAILED};
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
@@ -89,6 +89,31 @@ impl Money {
         Self::new_checked(amount, currency).expect(FAILED)
     }
 
+    /// Creates a new [`Money`] instance from the given `amount` decimal, rounded to the
+    /// precision of the `currency` (midpoints rounded to even).
+    ///
+    /// Banker's rounding avoids a systematic bias when many computed amounts (such as fees and
+    /// PnL) are accumulated.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `amount` is invalid outside the representable range [-9_223_372_036, 9_223_372_036].
+    pub fn from_decimal(amount: Decimal, currency: Currency) -> anyhow::Result<Self> {
+        let amount = amount.round_dp_with_strategy(
+            u32::from(currency.precision),
+            RoundingStrategy::MidpointNearestEven,
+        );
+        let amount_f64 = amount.to_f64().unwrap_or(f64::NAN);
+        check_in_range_inclusive_f64(amount_f64, MONEY_MIN, MONEY_MAX, "amount")?;
+
+        let scale = i128::pow(10, u32::from(FIXED_PRECISION) - amount.scale());
+        Ok(Self {
+            raw: (amount.mantissa() * scale) as i64,
+            currency,
+        })
+    }
+
     /// Creates a new [`Money`] instance from the given `raw` fixed-point value and the specified `currency`.
     #[must_use]
     pub fn from_raw(raw: i64, currency: Currency) -> Self {
@@ -125,6 +150,18 @@ impl Money {
     }
 }
 
+/// Rounds the `value` to the precision of the `currency` (midpoints rounded to even), returning
+/// the resulting [`Money`], as per [`Money::from_decimal`].
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `value` is outside the representable range [-9_223_372_036, 9_223_372_036].
+#[must_use]
+pub fn round_money(value: Decimal, currency: Currency) -> Money {
+    Money::from_decimal(value, currency).expect(FAILED)
+}
+
 impl FromStr for Money {
     type Err = String;
 
@@ -379,6 +416,43 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case(dec!(1010.12), "1010.12 USD")]
+    #[case(dec!(1010.125), "1010.12 USD")]
+    #[case(dec!(1010.135), "1010.14 USD")]
+    #[case(dec!(-1010.125), "-1010.12 USD")]
+    #[case(dec!(0.004), "0.00 USD")]
+    #[case(dec!(9223372036), "9223372036.00 USD")]
+    fn test_from_decimal(#[case] amount: Decimal, #[case] expected: &str) {
+        let money = Money::from_decimal(amount, Currency::USD()).unwrap();
+        assert_eq!(money, Money::from(expected));
+        assert_eq!(money.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_from_decimal_high_precision() {
+        let money = Money::from_decimal(dec!(0.123456789), Currency::BTC()).unwrap();
+        assert_eq!(money.raw, 123_456_790);
+        assert_eq!(money.as_decimal(), dec!(0.12345679));
+    }
+
+    #[rstest]
+    #[case(dec!(0.000000015), "0.00000002 BTC")]
+    #[case(dec!(0.000000025), "0.00000002 BTC")] // Midpoint rounded to even
+    #[case(dec!(-0.000000025), "-0.00000002 BTC")]
+    #[case(dec!(0.000000026), "0.00000003 BTC")]
+    #[case(dec!(1.12345678), "1.12345678 BTC")]
+    fn test_round_money(#[case] value: Decimal, #[case] expected: &str) {
+        let money = round_money(value, Currency::BTC());
+        assert_eq!(money, Money::from(expected));
+        assert_eq!(money.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_from_decimal_out_of_range() {
+        assert!(Money::from_decimal(dec!(9223372037), Currency::USD()).is_err());
+    }
+
     #[rstest]
     fn test_display() {
         let money = Money::new(1010.12, Currency::USD());
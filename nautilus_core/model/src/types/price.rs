@@ -476,6 +476,18 @@ mod tests {
         ));
     }
 
+    #[rstest]
+    fn test_as_decimal_from_raw() {
+        // Raw values are scaled to `FIXED_PRECISION`, so 123.45 is 123_450_000_000 raw
+        let price = Price::from_raw(123_450_000_000, 2);
+        assert_eq!(price.as_decimal(), dec!(123.45));
+        assert_eq!(price.as_decimal().scale(), 2);
+
+        // Exact for values with no exact `f64` representation
+        let price = Price::from_raw(1_234_567_123_456_789, 9);
+        assert_eq!(price.as_decimal(), dec!(1234567.123456789));
+    }
+
     #[rstest]
     fn test_with_maximum_value() {
         let price = Price::new(PRICE_MAX, 9);
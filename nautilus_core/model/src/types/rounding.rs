@@ -0,0 +1,149 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Rounding of fixed-point value types to an increment.
+
+use std::cmp::Ordering;
+
+use crate::types::{Price, Quantity};
+
+/// The direction in which to round a value to an increment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds down, toward negative infinity.
+    Floor,
+    /// Rounds up, toward positive infinity.
+    Ceil,
+    /// Rounds to the nearest increment, with ties rounding half-to-even (to the even multiple
+    /// of the increment).
+    Nearest,
+}
+
+/// Provides rounding of a fixed-point value type to a multiple of an increment.
+pub trait RoundToIncrement: Sized {
+    /// Returns the value rounded to the nearest multiple of `increment`, at the precision of the
+    /// `increment`.
+    ///
+    /// Midpoints round half-to-even as per [`RoundingMode::Nearest`], avoiding a systematic
+    /// bias when rounding many values. A non-positive `increment` leaves the value unchanged.
+    #[must_use]
+    fn round_to(self, increment: Self) -> Self;
+}
+
+impl RoundToIncrement for Price {
+    fn round_to(self, increment: Self) -> Self {
+        if increment.raw <= 0 {
+            return self;
+        }
+        let raw = round_raw_to_increment(
+            i128::from(self.raw),
+            i128::from(increment.raw),
+            RoundingMode::Nearest,
+        );
+        Self::from_raw(raw as i64, increment.precision)
+    }
+}
+
+impl RoundToIncrement for Quantity {
+    fn round_to(self, increment: Self) -> Self {
+        if increment.raw == 0 {
+            return self;
+        }
+        let raw = round_raw_to_increment(
+            i128::from(self.raw),
+            i128::from(increment.raw),
+            RoundingMode::Nearest,
+        );
+        Self::from_raw(raw as u64, increment.precision)
+    }
+}
+
+/// Rounds the raw fixed-point `value` to a multiple of the raw `increment` using the rounding
+/// `mode`.
+///
+/// A non-positive `increment` leaves `value` unchanged.
+pub(crate) fn round_raw_to_increment(value: i128, increment: i128, mode: RoundingMode) -> i128 {
+    if increment <= 0 {
+        return value;
+    }
+    let quotient = value.div_euclid(increment);
+    let remainder = value.rem_euclid(increment);
+    let quotient = match mode {
+        RoundingMode::Floor => quotient,
+        RoundingMode::Ceil if remainder > 0 => quotient + 1,
+        RoundingMode::Ceil => quotient,
+        RoundingMode::Nearest => match (remainder * 2).cmp(&increment) {
+            Ordering::Greater => quotient + 1,
+            Ordering::Equal if quotient % 2 != 0 => quotient + 1,
+            _ => quotient,
+        },
+    };
+    quotient * increment
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1.23", "0.05", "1.25")] // Up
+    #[case("1.22", "0.05", "1.20")] // Down
+    #[case("1.225", "0.05", "1.20")] // Midpoint to even (24 increments)
+    #[case("1.275", "0.05", "1.30")] // Midpoint to even (26 increments)
+    #[case("1.25", "0.05", "1.25")] // Aligned
+    #[case("-1.225", "0.05", "-1.20")] // Negative midpoint to even
+    #[case("-1.23", "0.05", "-1.25")] // Negative
+    #[case("2.5", "1", "2")] // Midpoint to even
+    #[case("3.5", "1", "4")] // Midpoint to even
+    fn test_price_round_to(#[case] value: &str, #[case] increment: &str, #[case] expected: &str) {
+        let result = Price::from(value).round_to(Price::from(increment));
+        assert_eq!(result, Price::from(expected));
+        assert_eq!(result.precision, Price::from(increment).precision);
+    }
+
+    #[rstest]
+    fn test_price_round_to_zero_increment() {
+        let price = Price::from("1.23");
+        assert_eq!(price.round_to(Price::from("0.00")), price);
+    }
+
+    #[rstest]
+    #[case("1.2346", "0.001", "1.235")] // Up
+    #[case("1.2344", "0.001", "1.234")] // Down
+    #[case("1.2345", "0.001", "1.234")] // Midpoint to even
+    #[case("1.2355", "0.001", "1.236")] // Midpoint to even
+    #[case("0.5", "1", "0")] // Midpoint to even
+    #[case("1.5", "1", "2")] // Midpoint to even
+    fn test_quantity_round_to(
+        #[case] value: &str,
+        #[case] increment: &str,
+        #[case] expected: &str,
+    ) {
+        let result = Quantity::from(value).round_to(Quantity::from(increment));
+        assert_eq!(result, Quantity::from(expected));
+        assert_eq!(result.precision, Quantity::from(increment).precision);
+    }
+
+    #[rstest]
+    fn test_quantity_round_to_zero_increment() {
+        let quantity = Quantity::from("1.234");
+        assert_eq!(quantity.round_to(Quantity::from("0.000")), quantity);
+    }
+}
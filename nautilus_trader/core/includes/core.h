@@ -25,6 +25,21 @@
  */
 #define NANOSECONDS_IN_MICROSECOND 1000
 
+/**
+ * The maximum number of seconds since the UNIX epoch representable as [`UnixNanos`].
+ */
+#define MAX_SECS (UINT64_MAX / NANOSECONDS_IN_SECOND)
+
+/**
+ * The maximum number of milliseconds since the UNIX epoch representable as [`UnixNanos`].
+ */
+#define MAX_MILLIS (UINT64_MAX / NANOSECONDS_IN_MILLISECOND)
+
+/**
+ * The maximum number of microseconds since the UNIX epoch representable as [`UnixNanos`].
+ */
+#define MAX_MICROS (UINT64_MAX / NANOSECONDS_IN_MICROSECOND)
+
 /**
  * `CVec` is a C compatible struct that stores an opaque pointer to a block of
  * memory, it's length and the capacity of the vector it was allocated from.
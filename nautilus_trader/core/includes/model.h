@@ -12,6 +12,11 @@
  */
 #define TRADE_ID_LEN 37
 
+/**
+ * The maximum number of levels returned by [`Instrument::price_ladder`].
+ */
+#define PRICE_LADDER_MAX_LEVELS 100000
+
 /**
  * The maximum fixed-point precision.
  */
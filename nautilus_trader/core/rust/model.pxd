@@ -10,6 +10,9 @@ cdef extern from "../includes/model.h":
     # The maximum length of ASCII characters for a `TradeId` string value (including null terminator).
     const uintptr_t TRADE_ID_LEN # = 37
 
+    # The maximum number of levels returned by [`Instrument::price_ladder`].
+    const uintptr_t PRICE_LADDER_MAX_LEVELS # = 100000
+
     # The maximum fixed-point precision.
     const uint8_t FIXED_PRECISION # = 9
 
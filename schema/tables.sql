@@ -79,6 +79,8 @@ CREATE TABLE IF NOT EXISTS "instrument" (
     margin_maint TEXT NOT NULL,
     maker_fee TEXT NULL,
     taker_fee TEXT NULL,
+    funding_rate TEXT NULL,
+    funding_interval_ns TEXT NULL,
     ts_event TEXT NOT NULL,
     ts_init TEXT NOT NULL,
     created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,